@@ -0,0 +1,148 @@
+//! Composable types of a library crate, which the integration tests compose from another crate,
+//! the way an application composes the modules of its libraries.
+
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+
+use juniper::{
+    FieldResult, GraphQLInputObject, GraphQLObject, GraphQLUnion, ScalarValue, graphql_interface,
+    graphql_object, graphql_subscription,
+};
+use juniper_compose::{
+    ComposableFromContext, composable_input_object, composable_interface, composable_object,
+    composable_subscription, composable_union, composite_object,
+};
+use std::{marker::PhantomData, pin::Pin};
+
+/// Context of the composed types, which the composing crate resolves them with.
+pub struct Context {
+    pub user_names: Vec<String>,
+}
+
+impl juniper::Context for Context {}
+
+#[derive(GraphQLObject)]
+pub struct User {
+    pub name: String,
+}
+
+#[derive(GraphQLObject)]
+pub struct Task {
+    pub title: String,
+}
+
+#[derive(Default)]
+pub struct UserQueries;
+
+#[composable_object]
+#[graphql_object(context = Context)]
+impl UserQueries {
+    fn user_count(context: &Context) -> i32 {
+        context.user_names.len().try_into().unwrap_or(i32::MAX)
+    }
+
+    fn user(context: &Context, index: i32) -> Option<User> {
+        let index = usize::try_from(index).ok()?;
+        let name = context.user_names.get(index)?.clone();
+        Some(User { name })
+    }
+}
+
+/// Generic over the scalar value type, so it is listed as `TaskQueries<_>`.
+pub struct TaskQueries<S>(PhantomData<S>);
+
+impl<S> Default for TaskQueries<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[composable_object]
+#[graphql_object(context = Context, scalar = S)]
+impl<S: ScalarValue + Send + Sync> TaskQueries<S> {
+    fn task_count() -> i32 {
+        2
+    }
+}
+
+/// Constructed from the context, so it is listed as `from_context ReportQueries`.
+pub struct ReportQueries {
+    user_count: usize,
+}
+
+impl ComposableFromContext for ReportQueries {
+    fn from_context(context: &Context) -> Self {
+        Self {
+            user_count: context.user_names.len(),
+        }
+    }
+}
+
+#[composable_object]
+#[graphql_object(context = Context)]
+impl ReportQueries {
+    fn report(&self) -> String {
+        format!("{} users", self.user_count)
+    }
+}
+
+// A composite of the library, composed again by the composing crate.
+composite_object!(pub LibraryQueries<Context = Context>(UserQueries, TaskQueries<_>));
+
+#[composable_interface]
+#[graphql_interface(context = Context)]
+pub trait Named {
+    fn name(&self) -> String;
+}
+
+#[derive(Default)]
+pub struct NameFields;
+
+#[composable_object]
+#[graphql_object(context = Context)]
+impl NameFields {
+    fn name() -> String {
+        "root".into()
+    }
+}
+
+#[composable_union]
+#[derive(GraphQLUnion)]
+#[graphql(context = Context)]
+pub enum UserResult {
+    User(User),
+}
+
+#[composable_union]
+#[derive(GraphQLUnion)]
+#[graphql(context = Context)]
+pub enum TaskResult {
+    Task(Task),
+}
+
+#[composable_input_object]
+#[derive(GraphQLInputObject)]
+pub struct UserFilter {
+    pub user_name: Option<String>,
+}
+
+#[composable_input_object]
+#[derive(GraphQLInputObject)]
+pub struct TaskFilter {
+    pub title: Option<String>,
+}
+
+#[derive(Default)]
+pub struct UserSubscriptions;
+
+type Stream<T> = Pin<Box<dyn juniper::futures::Stream<Item = T> + Send>>;
+
+#[composable_subscription]
+#[graphql_subscription(context = Context)]
+impl UserSubscriptions {
+    // juniper only supports asynchronous subscription resolvers.
+    #[allow(clippy::unused_async)]
+    async fn user_added() -> Stream<FieldResult<String>> {
+        Box::pin(juniper::futures::stream::iter([Ok("ada".to_owned())]))
+    }
+}
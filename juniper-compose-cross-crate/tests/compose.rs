@@ -0,0 +1,108 @@
+//! Composes the composable types of the library from this crate, which only sees their public
+//! items, and executes queries against the composed schema.
+
+#![warn(clippy::all)]
+#![warn(clippy::pedantic)]
+
+use juniper::{EmptyMutation, RootNode, Variables, graphql_value};
+use juniper_compose::{
+    ComposableObject, composite_input_object, composite_interface, composite_object,
+    composite_subscription, composite_union,
+};
+use juniper_compose_cross_crate::{
+    Context, LibraryQueries, NameFields, NamedValue, ReportQueries, Task, TaskFilter, TaskResult,
+    User, UserFilter, UserQueries, UserResult, UserSubscriptions,
+};
+
+composite_interface!(Node<Context = Context>(NamedValue) for = (NamedNode));
+composite_object!(NamedNode<Context = Context> impl (Node) (NameFields));
+composite_union!(SearchResult < Context = Context > (UserResult, TaskResult));
+composite_input_object!(Filter(UserFilter, TaskFilter));
+
+composite_object!(Query<Context = Context> federation (
+    LibraryQueries,
+    from_context ReportQueries,
+) {
+    fn node() -> NamedNode {
+        NamedNode
+    }
+
+    fn search(filter: Filter) -> Vec<SearchResult> {
+        let Filter(UserFilter { user_name }, TaskFilter { title }) = filter;
+        let users = user_name.map(|name| SearchResult::UserResult(UserResult::User(User { name })));
+        let tasks = title.map(|title| SearchResult::TaskResult(TaskResult::Task(Task { title })));
+        users.into_iter().chain(tasks).collect()
+    }
+});
+
+composite_subscription!(Subscription < Context = Context > (UserSubscriptions));
+
+composite_object!(RenamedQuery<Context = Context>(prefix users UserQueries { user: member }));
+
+fn context() -> Context {
+    Context {
+        user_names: vec!["ada".into(), "grace".into()],
+    }
+}
+
+#[test]
+fn composes_objects_of_another_crate() {
+    let schema = RootNode::new(Query, EmptyMutation::new(), Subscription);
+    let query = r#"{
+        userCount
+        user(index: 1) { name }
+        taskCount
+        report
+        node { name }
+        search(filter: { userName: "ada", title: "review" }) {
+            ... on User { name }
+            ... on Task { title }
+        }
+        _service { sdl }
+    }"#;
+    let (data, errors) =
+        juniper::execute_sync(query, None, &schema, &Variables::new(), &context()).unwrap();
+    assert!(errors.is_empty(), "{errors:?}");
+    let data = data.as_object_value().unwrap();
+    assert_eq!(data.get_field_value("userCount"), Some(&graphql_value!(2)));
+    assert_eq!(
+        data.get_field_value("user"),
+        Some(&graphql_value!({ "name": "grace" }))
+    );
+    assert_eq!(data.get_field_value("taskCount"), Some(&graphql_value!(2)));
+    assert_eq!(
+        data.get_field_value("report"),
+        Some(&graphql_value!("2 users"))
+    );
+    assert_eq!(
+        data.get_field_value("node"),
+        Some(&graphql_value!({ "name": "root" }))
+    );
+    assert_eq!(
+        data.get_field_value("search"),
+        Some(&graphql_value!([{ "name": "ada" }, { "title": "review" }])),
+    );
+    assert!(data.get_field_value("_service").is_some());
+}
+
+#[test]
+fn renames_fields_of_another_crate() {
+    assert_eq!(
+        <RenamedQuery as ComposableObject>::FIELDS,
+        ["usersUserCount", "member"],
+    );
+    let schema = RenamedQuery::build_schema();
+    let (data, errors) = juniper::execute_sync(
+        "{ usersUserCount member(index: 0) { name } }",
+        None,
+        &schema,
+        &Variables::new(),
+        &context(),
+    )
+    .unwrap();
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(
+        data,
+        graphql_value!({ "usersUserCount": 2, "member": { "name": "ada" } }),
+    );
+}
@@ -0,0 +1,860 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use syn::{
+    Attribute, Error, Expr, ExprLit, Fields, Generics, Ident, ImplItem, ImplItemFn, ItemEnum,
+    ItemImpl, ItemStruct, ItemTrait, Lit, LitStr, Meta, MetaNameValue, PredicateType, Result,
+    Token, TraitItem, Type, WherePredicate, ext::IdentExt, parse::Parse, parse_quote,
+    spanned::Spanned,
+};
+
+/// Kind of juniper type a composable is declared as.
+#[derive(Clone, Copy)]
+pub enum ComposableKind {
+    Object,
+    Subscription,
+    Interface,
+    InputObject,
+    Union,
+}
+
+impl ComposableKind {
+    fn attribute(self) -> &'static str {
+        match self {
+            Self::Object => "composable_object",
+            Self::Subscription => "composable_subscription",
+            Self::Interface => "composable_interface",
+            Self::InputObject => "composable_input_object",
+            Self::Union => "composable_union",
+        }
+    }
+
+    fn juniper_attribute(self) -> &'static str {
+        match self {
+            Self::Object => "graphql_object",
+            Self::Subscription => "graphql_subscription",
+            Self::Interface => "graphql_interface",
+            // The helper attribute of `#[derive(GraphQLInputObject)]` and
+            // `#[derive(GraphQLUnion)]`.
+            Self::InputObject | Self::Union => "graphql",
+        }
+    }
+
+    pub fn trait_name(self) -> &'static str {
+        match self {
+            Self::Object => "ComposableObject",
+            Self::Subscription => "ComposableSubscription",
+            Self::Interface => "ComposableInterface",
+            Self::InputObject => "ComposableInputObject",
+            Self::Union => "ComposableUnion",
+        }
+    }
+
+    /// Name of the `juniper_compose::check` function asserting that a type is a composable of
+    /// this kind.
+    pub fn assert_fn(self) -> Ident {
+        format_ident!("assert_{}", self.attribute())
+    }
+
+    /// Name of the associated constant of the composable trait listing the names a composable
+    /// contributes to a composite: its fields, or the variant types of a union.
+    pub fn names_const(self) -> Ident {
+        let name = match self {
+            Self::Union => "VARIANTS",
+            _ => "FIELDS",
+        };
+        Ident::new(name, Span::call_site())
+    }
+
+    pub fn trait_path(self) -> TokenStream {
+        let trait_name = Ident::new(self.trait_name(), Span::call_site());
+        quote! { ::juniper_compose::#trait_name }
+    }
+}
+
+/// Arguments of `#[composable_object(...)]` and `#[composable_subscription(...)]` themselves.
+#[derive(Default)]
+pub struct ComposableArgs {
+    /// `default`, which implements `Default` for the (field-less) type.
+    default: Option<kw::default>,
+}
+
+impl Parse for ComposableArgs {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let mut args = Self::default();
+        if !input.is_empty() {
+            args.default = Some(
+                input
+                    .parse()
+                    .map_err(|error| Error::new(error.span(), "expected `default`"))?,
+            );
+        }
+        Ok(args)
+    }
+}
+
+mod kw {
+    syn::custom_keyword!(default);
+}
+
+pub fn expand(kind: ComposableKind, args: &ComposableArgs, item_impl: &ItemImpl) -> TokenStream {
+    let mut expanded = expand_composable(kind, item_impl).unwrap_or_else(Error::into_compile_error);
+    expanded.extend(expand_default(args, item_impl));
+    if let Err(error) = check_attribute_order(kind, item_impl) {
+        expanded.extend(error.into_compile_error());
+    }
+    expanded
+}
+
+/// Arguments of the `#[graphql_object(...)]` (or `#[graphql_subscription(...)]`,
+/// `#[graphql_interface(...)]`) attribute that `#[composable_object]` needs to know about.
+/// Everything else is left for juniper to interpret.
+#[derive(Default)]
+struct GraphQLObjectArgs {
+    scalar: Option<ScalarArg>,
+    /// `context = ...` type the fields are resolved with.
+    context: Option<Type>,
+    /// Name of the enum `#[graphql_interface]` generates to represent the interface.
+    enum_ident: Option<Ident>,
+    /// `rename_all = "..."` policy juniper names the fields with.
+    rename_all: RenamePolicy,
+}
+
+/// Value of the `scalar = ...` argument, parsed the way juniper parses it.
+enum ScalarArg {
+    Concrete(Type),
+    Generic(PredicateType),
+}
+
+impl GraphQLObjectArgs {
+    fn from_attrs(kind: ComposableKind, attrs: &[Attribute]) -> Result<Self> {
+        let attr = attrs
+            .iter()
+            .find(|attr| is_attribute(attr, kind.juniper_attribute()));
+        match attr.map(|attr| &attr.meta) {
+            Some(Meta::List(list)) => list.parse_args(),
+            _ => Ok(Self::default()),
+        }
+    }
+}
+
+/// Returns the context and the concrete scalar value type the `#[graphql_object(...)]` attribute
+/// among `attrs` declares, if any.
+pub fn object_context_and_scalar(attrs: &[Attribute]) -> Result<(Option<Type>, Option<Type>)> {
+    let args = GraphQLObjectArgs::from_attrs(ComposableKind::Object, attrs)?;
+    let scalar = match args.scalar {
+        Some(ScalarArg::Concrete(scalar)) => Some(scalar),
+        Some(ScalarArg::Generic(_)) | None => None,
+    };
+    Ok((args.context, scalar))
+}
+
+impl Parse for GraphQLObjectArgs {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let mut args = Self::default();
+        while !input.is_empty() {
+            let key = input.call(Ident::parse_any)?;
+            if key == "scalar" {
+                input.parse::<Token![=]>()?;
+                args.scalar = Some(if input.fork().parse::<WherePredicate>().is_ok() {
+                    match input.parse::<WherePredicate>()? {
+                        WherePredicate::Type(predicate) => ScalarArg::Generic(predicate),
+                        predicate => {
+                            return Err(Error::new(
+                                predicate.span(),
+                                "only type predicates are allowed here",
+                            ));
+                        }
+                    }
+                } else {
+                    ScalarArg::Concrete(input.parse()?)
+                });
+            } else if key == "context" || key == "ctx" || key == "Context" {
+                input.parse::<Token![=]>()?;
+                args.context = Some(input.parse()?);
+            } else if key == "enum" {
+                input.parse::<Token![=]>()?;
+                args.enum_ident = Some(input.parse()?);
+            } else if key == "rename_all" {
+                input.parse::<Token![=]>()?;
+                args.rename_all = RenamePolicy::from_lit(&input.parse()?);
+            } else {
+                while !input.is_empty() && !input.peek(Token![,]) {
+                    input.parse::<TokenTree>()?;
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(args)
+    }
+}
+
+/// Renaming policy of `rename_all = "..."`, mirroring juniper's. Unknown policies are left for
+/// juniper to report.
+#[derive(Clone, Copy, Default)]
+enum RenamePolicy {
+    None,
+    #[default]
+    CamelCase,
+    ScreamingSnakeCase,
+}
+
+impl RenamePolicy {
+    fn from_lit(lit: &LitStr) -> Self {
+        match lit.value().as_str() {
+            "none" => Self::None,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnakeCase,
+            _ => Self::CamelCase,
+        }
+    }
+
+    fn apply(self, name: &str) -> String {
+        match self {
+            Self::None => name.to_owned(),
+            Self::CamelCase => to_camel_case(name),
+            Self::ScreamingSnakeCase => to_upper_snake_case(name),
+        }
+    }
+}
+
+/// Converts `name` to camelCase the way juniper names fields: a leading `_` is dropped and `__`
+/// kept, and each part after an underscore only has its first letter uppercased.
+pub fn to_camel_case(name: &str) -> String {
+    let mut camel = String::new();
+    let rest = if let Some(rest) = name.strip_prefix("__") {
+        camel.push_str("__");
+        rest
+    } else {
+        name.strip_prefix('_').unwrap_or(name)
+    };
+    for (index, part) in rest.split('_').enumerate() {
+        let mut chars = part.chars();
+        if index == 0 {
+            camel.push_str(part);
+        } else if let Some(first) = chars.next() {
+            camel.extend(first.to_uppercase());
+            camel.push_str(chars.as_str());
+        }
+    }
+    camel
+}
+
+fn to_upper_snake_case(name: &str) -> String {
+    let mut after_lowercase = false;
+    let mut upper = String::new();
+    for char in name.chars() {
+        if char == '_' {
+            after_lowercase = false;
+        } else if char.is_lowercase() {
+            after_lowercase = true;
+        } else if char.is_uppercase() {
+            if after_lowercase {
+                upper.push('_');
+            }
+            after_lowercase = false;
+        }
+        upper.extend(char.to_uppercase());
+    }
+    upper
+}
+
+/// Returns the name juniper gives the field declared as `ident` with `attrs`: the name given
+/// with `#[graphql(name = "...")]`, or `ident` renamed by `policy`.
+fn field_name(attrs: &[Attribute], ident: &Ident, policy: RenamePolicy) -> Result<LitStr> {
+    if let Some(name) = graphql_str_arg(attrs, &["name"])? {
+        return Ok(name);
+    }
+    Ok(LitStr::new(
+        &policy.apply(&ident.unraw().to_string()),
+        Span::call_site(),
+    ))
+}
+
+/// Returns `true` if `name`, the name juniper gives the field `ident` declared with `attrs`, was
+/// converted from `ident` by a `rename_all` policy rather than given explicitly.
+fn is_converted(attrs: &[Attribute], ident: &Ident, name: &LitStr) -> Result<bool> {
+    Ok(graphql_str_arg(attrs, &["name"])?.is_none() && ident.unraw() != name.value())
+}
+
+/// Returns the description juniper gives the field declared with `attrs`: the one given with
+/// `#[graphql(description = "...")]`, or its doc comments, joined the way juniper joins them.
+fn field_description(attrs: &[Attribute]) -> Result<Option<String>> {
+    if let Some(description) = graphql_str_arg(attrs, &["description", "desc"])? {
+        return Ok(Some(description.value()));
+    }
+    let lines = attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(MetaNameValue {
+                path,
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(line),
+                        ..
+                    }),
+                ..
+            }) if path.is_ident("doc") => Some(line.value()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if lines.is_empty() {
+        return Ok(None);
+    }
+    // Lines are trimmed and joined with newlines, or with a space after a continuation backslash.
+    let mut description = String::new();
+    for (index, line) in lines.iter().enumerate() {
+        let line = line.trim_end();
+        let line = line.strip_prefix(' ').unwrap_or(line);
+        if index == lines.len() - 1 {
+            description.push_str(line);
+        } else if line.ends_with('\\') {
+            description.push_str(line.trim_end_matches('\\'));
+            description.push(' ');
+        } else {
+            description.push_str(line);
+            description.push('\n');
+        }
+    }
+    Ok(Some(description))
+}
+
+/// Returns the first string argument named one of `keys` among the `#[graphql(...)]` attributes
+/// in `attrs`.
+fn graphql_str_arg(attrs: &[Attribute], keys: &[&str]) -> Result<Option<LitStr>> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("graphql")) {
+        if let Meta::List(list) = &attr.meta
+            && let Some(value) =
+                list.parse_args_with(|input: syn::parse::ParseStream| parse_str_arg(input, keys))?
+        {
+            return Ok(Some(value));
+        }
+    }
+    Ok(None)
+}
+
+/// Parses the `key = "..."` argument, `key` being one of `keys`, out of the arguments of a
+/// `#[graphql(...)]` attribute. Everything else is left for juniper to interpret.
+fn parse_str_arg(input: syn::parse::ParseStream, keys: &[&str]) -> Result<Option<LitStr>> {
+    let mut value = None;
+    while !input.is_empty() {
+        let key = input.call(Ident::parse_any)?;
+        if keys.iter().any(|name| key == name) && input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            value = Some(input.parse()?);
+        } else {
+            while !input.is_empty() && !input.peek(Token![,]) {
+                input.parse::<TokenTree>()?;
+            }
+        }
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+    Ok(value)
+}
+
+pub fn is_attribute(attr: &Attribute, name: &str) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == name)
+}
+
+/// The juniper attribute consumes the `#[graphql(...)]` attributes that `#[composable_object]`
+/// needs to see, so it must expand last. Once it has expanded, the impl no longer carries the
+/// juniper attribute but does carry the lint allowances juniper adds to its output.
+fn check_attribute_order(kind: ComposableKind, item_impl: &ItemImpl) -> Result<()> {
+    let has_juniper_attribute = item_impl
+        .attrs
+        .iter()
+        .any(|attr| is_attribute(attr, kind.juniper_attribute()));
+    let expanded_by_juniper = item_impl.attrs.iter().any(|attr| {
+        attr.path().is_ident("allow")
+            && attr
+                .meta
+                .require_list()
+                .is_ok_and(|list| list.tokens.to_string().contains("missing_errors_doc"))
+    });
+    if !has_juniper_attribute && expanded_by_juniper {
+        return Err(Error::new(
+            Span::call_site(),
+            format!(
+                "apply #[{}] above #[{}]",
+                kind.attribute(),
+                kind.juniper_attribute(),
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn expand_composable(kind: ComposableKind, item_impl: &ItemImpl) -> Result<TokenStream> {
+    // The fields are those of the inherent impl juniper expands, which a type implementing the
+    // juniper traits by hand doesn't have.
+    if let Some((_, path, _)) = &item_impl.trait_ {
+        return Err(Error::new(
+            path.span(),
+            format!(
+                "#[{}] only applies to an inherent impl with #[{}]; implement `{}` by hand for a \
+                 type implementing the juniper traits itself",
+                kind.attribute(),
+                kind.juniper_attribute(),
+                kind.trait_name(),
+            ),
+        ));
+    }
+    let ty = &item_impl.self_ty;
+    let args = GraphQLObjectArgs::from_attrs(kind, &item_impl.attrs)?;
+    let composable_trait = kind.trait_path();
+
+    // Methods juniper ignores are plain methods rather than fields.
+    let methods = item_impl
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Fn(method) if !is_ignored(&method.attrs) => Some(method),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let field_names = methods
+        .iter()
+        .map(|method| field_name(&method.attrs, &method.sig.ident, args.rename_all))
+        .collect::<Result<Vec<_>>>()?;
+    let fields = &field_names;
+    let (generics, scalar) = composable_generics(kind, &item_impl.generics, args.scalar);
+    // Only `ComposableObject` has a type name, and subscription fields are always resolved
+    // asynchronously.
+    let object_items = match kind {
+        ComposableKind::Object => {
+            let async_fields = methods
+                .iter()
+                .zip(&field_names)
+                .filter(|(method, _)| method.sig.asyncness.is_some())
+                .map(|(_, name)| name);
+            let converted_fields = methods
+                .iter()
+                .zip(&field_names)
+                .filter_map(|(method, name)| {
+                    is_converted(&method.attrs, &method.sig.ident, name)
+                        .map(|converted| converted.then_some(name))
+                        .transpose()
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let field_types = expand_field_types(&scalar, fields);
+            Some(quote! {
+                const TYPE_NAME: &'static str =
+                    <Self as ::juniper::macros::reflect::BaseType<#scalar>>::NAME;
+                const ASYNC_FIELDS: &'static [&'static str] = &[#( #async_fields ),*];
+                const CONVERTED_FIELDS: &'static [&'static str] = &[#( #converted_fields ),*];
+                const FIELD_TYPES: &'static [::juniper_compose::FieldType] = #field_types;
+            })
+        }
+        _ => None,
+    };
+    let describe_fields = match kind {
+        ComposableKind::Object => Some(expand_describe_fields(&methods, fields)?),
+        _ => None,
+    };
+    let fields_auth = match kind {
+        ComposableKind::Object => Some(expand_fields_auth(&methods, fields)?),
+        _ => None,
+    };
+    // juniper doesn't know `#[auth(...)]`, so it never sees it.
+    let item_impl = match kind {
+        ComposableKind::Object => strip_auth(item_impl),
+        _ => item_impl.clone(),
+    };
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #composable_trait<#scalar> for #ty #where_clause {
+            const FIELDS: &'static [&'static str] = &[#( #fields ),*];
+            #object_items
+            #describe_fields
+            #fields_auth
+        }
+
+        #item_impl
+    })
+}
+
+/// Lists the types of `fields`, which juniper describes in the `FieldMeta` it implements for each
+/// field of an object or interface.
+fn expand_field_types(scalar: &Type, fields: &[LitStr]) -> TokenStream {
+    let field_types = fields.iter().map(|field| {
+        let meta = quote! {
+            <Self as ::juniper::macros::reflect::FieldMeta<
+                #scalar,
+                { ::juniper::macros::reflect::fnv1a128(#field) },
+            >>
+        };
+        quote! {
+            ::juniper_compose::FieldType {
+                name: #meta::TYPE,
+                sub_types: #meta::SUB_TYPES,
+                wrapped: #meta::WRAPPED_VALUE,
+            }
+        }
+    });
+    quote! { &[#( #field_types ),*] }
+}
+
+/// Lists the descriptions of the fields of a composable object, so that `describe_fields` doesn't
+/// build its meta type.
+fn expand_describe_fields(methods: &[&ImplItemFn], fields: &[LitStr]) -> Result<TokenStream> {
+    let descriptions = methods
+        .iter()
+        .map(|method| {
+            let description = field_description(&method.attrs)?;
+            Ok(if let Some(description) = description {
+                quote! { ::std::option::Option::Some(#description) }
+            } else {
+                quote! { ::std::option::Option::None }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(quote! {
+        fn describe_fields() -> &'static [(&'static str, ::std::option::Option<&'static str>)] {
+            &[#( (#fields, #descriptions) ),*]
+        }
+    })
+}
+
+/// Lists the roles the fields of a composable object require, given with `#[auth(role = "...")]`
+/// on their resolvers.
+fn expand_fields_auth(methods: &[&ImplItemFn], fields: &[LitStr]) -> Result<TokenStream> {
+    let roles = methods
+        .iter()
+        .map(|method| {
+            let role = field_role(&method.attrs)?;
+            Ok(if let Some(role) = role {
+                quote! { ::std::option::Option::Some(#role) }
+            } else {
+                quote! { ::std::option::Option::None }
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(quote! {
+        fn fields_auth() -> &'static [(&'static str, ::std::option::Option<&'static str>)] {
+            &[#( (#fields, #roles) ),*]
+        }
+    })
+}
+
+/// Returns the role given with `#[auth(role = "...")]` among `attrs`, if any.
+fn field_role(attrs: &[Attribute]) -> Result<Option<LitStr>> {
+    let mut role = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("auth")) {
+        if role.is_some() {
+            return Err(Error::new(attr.span(), "duplicate `#[auth(...)]`"));
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("role") {
+                role = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `role`"))
+            }
+        })?;
+        if role.is_none() {
+            return Err(Error::new(attr.span(), "expected `role = \"...\"`"));
+        }
+    }
+    Ok(role)
+}
+
+/// Returns `item_impl` without the `#[auth(...)]` attributes of its methods.
+fn strip_auth(item_impl: &ItemImpl) -> ItemImpl {
+    let mut item_impl = item_impl.clone();
+    for item in &mut item_impl.items {
+        if let ImplItem::Fn(method) = item {
+            method.attrs.retain(|attr| !attr.path().is_ident("auth"));
+        }
+    }
+    item_impl
+}
+
+/// Implements `Default` for the type of a composable declared `default`, as it is constructed
+/// that way by the composite. It only compiles for types without fields.
+fn expand_default(args: &ComposableArgs, item_impl: &ItemImpl) -> Option<TokenStream> {
+    let keyword = args.default?;
+    let ty = &item_impl.self_ty;
+    let (impl_generics, _, where_clause) = item_impl.generics.split_for_impl();
+    Some(quote_spanned! {keyword.span=>
+        impl #impl_generics ::std::default::Default for #ty #where_clause {
+            fn default() -> Self {
+                Self {}
+            }
+        }
+    })
+}
+
+/// Expands `#[composable_interface]`, which implements `ComposableInterface` for the enum
+/// `#[graphql_interface]` generates for the trait.
+pub fn expand_interface(item_trait: &ItemTrait) -> TokenStream {
+    expand_composable_interface(item_trait).unwrap_or_else(|error| {
+        let mut expanded = error.into_compile_error();
+        item_trait.to_tokens(&mut expanded);
+        expanded
+    })
+}
+
+fn expand_composable_interface(item_trait: &ItemTrait) -> Result<TokenStream> {
+    let kind = ComposableKind::Interface;
+    if !item_trait
+        .attrs
+        .iter()
+        .any(|attr| is_attribute(attr, kind.juniper_attribute()))
+    {
+        return Err(Error::new(
+            Span::call_site(),
+            format!(
+                "apply #[{}] above #[{}]",
+                kind.attribute(),
+                kind.juniper_attribute(),
+            ),
+        ));
+    }
+    if !item_trait.generics.params.is_empty() {
+        return Err(Error::new(
+            item_trait.generics.span(),
+            "generic interfaces can't be composed",
+        ));
+    }
+    let args = GraphQLObjectArgs::from_attrs(kind, &item_trait.attrs)?;
+    let composable_trait = kind.trait_path();
+    let ty = args
+        .enum_ident
+        .unwrap_or_else(|| format_ident!("{}Value", item_trait.ident));
+
+    let fields = item_trait
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            TraitItem::Fn(method) if !is_ignored(&method.attrs) => Some(method),
+            _ => None,
+        })
+        .map(|method| field_name(&method.attrs, &method.sig.ident, args.rename_all))
+        .collect::<Result<Vec<_>>>()?;
+
+    let (generics, scalar) = composable_generics(kind, &item_trait.generics, args.scalar);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let field_types = expand_field_types(&scalar, &fields);
+
+    Ok(quote! {
+        impl #impl_generics #composable_trait<#scalar> for #ty #where_clause {
+            const FIELDS: &'static [&'static str] = &[#( #fields ),*];
+            const FIELD_TYPES: &'static [::juniper_compose::FieldType] = #field_types;
+        }
+
+        #item_trait
+    })
+}
+
+/// Expands `#[composable_input_object]`, which implements `ComposableInputObject` for a struct
+/// deriving `GraphQLInputObject`.
+pub fn expand_input_object(item_struct: &ItemStruct) -> TokenStream {
+    expand_composable_input_object(item_struct).unwrap_or_else(|error| {
+        let mut expanded = error.into_compile_error();
+        item_struct.to_tokens(&mut expanded);
+        expanded
+    })
+}
+
+fn expand_composable_input_object(item_struct: &ItemStruct) -> Result<TokenStream> {
+    let kind = ComposableKind::InputObject;
+    let Fields::Named(named_fields) = &item_struct.fields else {
+        return Err(Error::new(
+            item_struct.ident.span(),
+            "composable input objects must have named fields",
+        ));
+    };
+    let args = GraphQLObjectArgs::from_attrs(kind, &item_struct.attrs)?;
+    let composable_trait = kind.trait_path();
+    let ident = &item_struct.ident;
+
+    let fields = named_fields
+        .named
+        .iter()
+        .filter(|field| !is_ignored(&field.attrs))
+        .filter_map(|field| Some((&field.attrs, field.ident.as_ref()?)))
+        .map(|(attrs, ident)| field_name(attrs, ident, args.rename_all))
+        .collect::<Result<Vec<_>>>()?;
+
+    let (generics, scalar) = composable_generics(kind, &item_struct.generics, args.scalar);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = item_struct.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #composable_trait<#scalar> for #ident #ty_generics #where_clause {
+            const FIELDS: &'static [&'static str] = &[#( #fields ),*];
+        }
+
+        #item_struct
+    })
+}
+
+/// Expands `#[composable_union]`, which implements `ComposableUnion` for an enum deriving
+/// `GraphQLUnion`.
+pub fn expand_union(item_enum: &ItemEnum) -> TokenStream {
+    expand_composable_union(item_enum).unwrap_or_else(|error| {
+        let mut expanded = error.into_compile_error();
+        item_enum.to_tokens(&mut expanded);
+        expanded
+    })
+}
+
+fn expand_composable_union(item_enum: &ItemEnum) -> Result<TokenStream> {
+    let kind = ComposableKind::Union;
+    let args = GraphQLObjectArgs::from_attrs(kind, &item_enum.attrs)?;
+    let composable_trait = kind.trait_path();
+    let ident = &item_enum.ident;
+
+    let (generics, scalar) = composable_generics(kind, &item_enum.generics, args.scalar);
+    // Variants that juniper ignores aren't variants of the union, and other malformed variants
+    // are left for juniper to report. Types given an external resolver with
+    // `#[graphql(on Type = resolver)]` are variants as well, unless a variant already holds them.
+    // Types are compared as they are written, like juniper merges them: the same type written
+    // differently, e.g. `Ewok` and `crate::Ewok`, has conflicting implementations of juniper's
+    // marker trait, so it is rejected before `VARIANTS` could list it twice.
+    let mut variant_types = item_enum
+        .variants
+        .iter()
+        .filter(|variant| !is_ignored(&variant.attrs))
+        .filter_map(|variant| match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                Some(fields.unnamed[0].ty.clone())
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    for ty in external_resolver_types(&item_enum.attrs)? {
+        let written = ty.to_token_stream().to_string();
+        if !variant_types
+            .iter()
+            .any(|variant| variant.to_token_stream().to_string() == written)
+        {
+            variant_types.push(ty);
+        }
+    }
+    let variants = variant_types
+        .iter()
+        .map(|ty| quote! { <#ty as ::juniper::macros::reflect::BaseType<#scalar>>::NAME });
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = item_enum.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #composable_trait<#scalar> for #ident #ty_generics #where_clause {
+            const VARIANTS: &'static [&'static str] = &[#( #variants ),*];
+        }
+
+        #item_enum
+    })
+}
+
+/// Returns the types given an external resolver with `on Type = resolver` in the
+/// `#[graphql(...)]` attributes among `attrs`. Other arguments are left for juniper to interpret.
+fn external_resolver_types(attrs: &[Attribute]) -> Result<Vec<Type>> {
+    let mut types = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("graphql")) {
+        let Meta::List(list) = &attr.meta else {
+            continue;
+        };
+        list.parse_args_with(|input: syn::parse::ParseStream| {
+            while !input.is_empty() {
+                let key = input.call(Ident::parse_any)?;
+                if key == "on" {
+                    types.push(input.parse::<Type>()?);
+                    input.parse::<Token![=]>()?;
+                    input.parse::<syn::ExprPath>()?;
+                } else {
+                    while !input.is_empty() && !input.peek(Token![,]) {
+                        input.parse::<TokenTree>()?;
+                    }
+                }
+                if !input.is_empty() {
+                    input.parse::<Token![,]>()?;
+                }
+            }
+            Ok(())
+        })?;
+    }
+    Ok(types)
+}
+
+/// Returns `true` if `attrs` include `#[graphql(ignore)]` (or its `skip` alias).
+fn is_ignored(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("graphql"))
+        .filter_map(|attr| attr.meta.require_list().ok())
+        .any(|list| {
+            list.tokens.clone().into_iter().any(|token| {
+                matches!(&token, TokenTree::Ident(ident) if ident == "ignore" || ident == "skip")
+            })
+        })
+}
+
+/// Mirrors how juniper picks the scalar: a concrete type, one of the item's own type
+/// parameters, or an implicit `__S` type parameter when none is given. Returns the generics of
+/// the `Composable*` impl along with the scalar it is implemented for.
+fn composable_generics(
+    kind: ComposableKind,
+    item_generics: &Generics,
+    scalar: Option<ScalarArg>,
+) -> (Generics, Type) {
+    let mut generics = item_generics.clone();
+    if !generics.params.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote! { Self: ::std::marker::Sync });
+    }
+    let scalar: Type = match scalar {
+        Some(ScalarArg::Concrete(scalar)) => {
+            let is_type_param = item_generics.type_params().any(|param| {
+                matches!(&scalar, Type::Path(path) if path.qself.is_none()
+                    && path.path.is_ident(&param.ident))
+            });
+            if is_type_param {
+                push_scalar_bounds(kind, &mut generics, &scalar);
+            }
+            scalar
+        }
+        Some(ScalarArg::Generic(predicate)) => {
+            let scalar = predicate.bounded_ty.clone();
+            generics.params.push(parse_quote! { #scalar });
+            push_scalar_bounds(kind, &mut generics, &scalar);
+            generics
+                .make_where_clause()
+                .predicates
+                .push(WherePredicate::Type(predicate));
+            scalar
+        }
+        None => {
+            let scalar = parse_quote! { __S };
+            generics.params.push(parse_quote! { #scalar });
+            push_scalar_bounds(kind, &mut generics, &scalar);
+            scalar
+        }
+    };
+    (generics, scalar)
+}
+
+/// Interfaces are only used for their meta type and input objects are never resolved, so unlike
+/// objects and unions they don't need a scalar value type that can be resolved asynchronously.
+fn push_scalar_bounds(kind: ComposableKind, generics: &mut Generics, scalar: &Type) {
+    let predicate = match kind {
+        ComposableKind::Object | ComposableKind::Subscription | ComposableKind::Union => {
+            parse_quote! {
+                #scalar: ::juniper::ScalarValue + ::std::marker::Send + ::std::marker::Sync
+            }
+        }
+        ComposableKind::Interface | ComposableKind::InputObject => {
+            parse_quote! { #scalar: ::juniper::ScalarValue }
+        }
+    };
+    generics.make_where_clause().predicates.push(predicate);
+}
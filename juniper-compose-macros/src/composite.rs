@@ -0,0 +1,2856 @@
+use crate::composable::{ComposableKind, to_camel_case};
+use proc_macro2::{Group, Literal, Span, TokenStream, TokenTree};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use syn::{
+    Attribute, Error, Expr, ExprLit, Ident, ImplItem, Lifetime, LifetimeParam, Lit, LitStr, Meta,
+    MetaNameValue, Path, PathArguments, Result, Token, Type, TypeParam, TypeParamBound, Visibility,
+    WhereClause, braced,
+    ext::IdentExt,
+    parenthesized,
+    parse::Parse,
+    parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token::{Brace, Comma, Paren},
+};
+
+/// Input of the `composite_object!`, `composite_subscription!`, `composite_input_object!` and
+/// `composite_union!` macros, which `composite_interface!` input starts with.
+pub struct CompositeInput {
+    /// Attributes of the invocation other than `#[graphql(...)]`, doc comments included, which
+    /// the generated type carries.
+    pub attrs: Vec<Attribute>,
+    /// Description of the GraphQL type, from `#[graphql(description = "...")]` or the doc
+    /// comments. An empty description is the same as none.
+    description: Option<LitStr>,
+    pub vis: Visibility,
+    pub ident: Ident,
+    /// GraphQL name given with `as "Name"`, if it differs from the identifier.
+    graphql_name: Option<LitStr>,
+    params: Option<CompositeParams>,
+    /// `sync` keyword, which requires every field to be resolvable synchronously.
+    sync: Option<kw::sync>,
+    /// `sorted` keyword, which registers the fields sorted by name rather than in the order the
+    /// members are listed.
+    sorted: Option<kw::sorted>,
+    /// `federation` keyword, which composes [`federation_member`] into the composite.
+    federation: Option<kw::federation>,
+    /// `generic_members` keyword, which lists every member written without generic arguments as
+    /// if it were written `Member<_>`.
+    generic_members: Option<kw::generic_members>,
+    /// `rename_all = "..."` argument, whose case every member converts its exposed names to.
+    rename_all: Option<(kw::rename_all, Case)>,
+    /// `map_err = path` argument, the function the errors of the fields the members resolve are
+    /// mapped with.
+    map_err: Option<(kw::map_err, Path)>,
+    /// Interfaces listed in `impl (...)`, which the composite implements.
+    interfaces: Option<(Token![impl], Punctuated<Type, Comma>)>,
+    #[allow(dead_code)]
+    paren: Paren,
+    pub composables: Punctuated<CompositeMember, Comma>,
+    /// `where` clause after the members, whose predicates bound every impl of the composite.
+    pub where_clause: Option<WhereClause>,
+    /// `info = expr` argument after the members, the type info the composite is built with.
+    info_value: Option<(kw::info, Expr)>,
+    /// Resolvers of the fields the composite defines itself, in braces after the members.
+    inline_fields: Option<InlineFields>,
+    /// Whether the composite is implemented for every scalar value type when `ScalarValue` isn't
+    /// specified, rather than for `DefaultScalarValue`.
+    generic_scalar: bool,
+}
+
+impl CompositeInput {
+    /// Returns the GraphQL name of the composite, which is its identifier unless overridden.
+    pub fn name_lit(&self) -> LitStr {
+        self.graphql_name
+            .clone()
+            .unwrap_or_else(|| LitStr::new(&self.ident.to_string(), Span::call_site()))
+    }
+
+    pub fn context(&self) -> Type {
+        self.explicit_context()
+            .unwrap_or_else(|| parse_quote! { () })
+    }
+
+    /// Returns the type info of the composite, which its members share.
+    pub fn info(&self) -> Type {
+        self.explicit_info().unwrap_or_else(|| parse_quote! { () })
+    }
+
+    /// Returns `true` if the type info is specified, in which case the fields of the composite
+    /// may depend on it.
+    pub fn has_info(&self) -> bool {
+        self.explicit_info().is_some()
+    }
+
+    /// Returns the scalar value type the composite is implemented for, which is the type
+    /// parameter declared as `S: ScalarValue`, or `__S`, if the scalar is generic.
+    pub fn scalar(&self) -> Type {
+        if self.has_generic_scalar() {
+            let ident = self.generic_scalar_ident();
+            parse_quote! { #ident }
+        } else {
+            self.const_scalar()
+        }
+    }
+
+    /// Returns `true` if the composite is implemented for every scalar value type, as the type
+    /// parameter returned by [`Self::scalar`].
+    pub fn has_generic_scalar(&self) -> bool {
+        self.generic_scalar_param().is_some()
+            || self.generic_scalar && self.explicit_scalar().is_none()
+    }
+
+    fn generic_scalar_ident(&self) -> Ident {
+        self.generic_scalar_param()
+            .map_or_else(|| format_ident!("__S"), |param| param.ident.clone())
+    }
+
+    /// Returns a concrete scalar value type to instantiate the composite with in constant
+    /// evaluation.
+    pub fn const_scalar(&self) -> Type {
+        self.explicit_scalar()
+            .unwrap_or_else(|| parse_quote! { ::juniper::DefaultScalarValue })
+    }
+
+    /// Makes the composite implemented for every scalar value type unless `ScalarValue` is
+    /// specified. Members can't be instantiated with a generic scalar value type, as the type of
+    /// the composite holds them.
+    pub fn make_scalar_generic(&mut self) -> Result<()> {
+        self.generic_scalar = true;
+        if self.explicit_scalar().is_none()
+            && let Some(member) = self
+                .composables
+                .iter()
+                .find(|composable| composable.scalar_placeholder.is_some())
+        {
+            return Err(Error::new(
+                member.ty.span(),
+                "specify the scalar value type of the composite with `ScalarValue = ...` to \
+                 instantiate members with it",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Members listed with `_` are instantiated with a concrete scalar value type, which a composite
+    /// declaring a scalar value type parameter doesn't have.
+    fn reject_generic_scalar_placeholders(&self) -> Result<()> {
+        if self.generic_scalar_param().is_some()
+            && let Some(member) = self
+                .composables
+                .iter()
+                .find(|composable| composable.scalar_placeholder.is_some())
+        {
+            return Err(Error::new(
+                member.ty.span(),
+                "members of a composite generic over its scalar value type can't be instantiated \
+                 with it, declare them generic over every scalar value type instead",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the generic parameters and where clause predicates to implement the composite's
+    /// traits with, which introduce the scalar value type parameter if the scalar is generic,
+    /// along with the bounds it is declared with. The predicates include the ones of the `where`
+    /// clause of the invocation, and end with a comma.
+    pub fn impl_generics(&self) -> (TokenStream, TokenStream) {
+        let predicates = self
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| &where_clause.predicates);
+        let lifetimes = self.lifetimes().collect::<Vec<_>>();
+        if self.has_generic_scalar() {
+            let ident = self.generic_scalar_ident();
+            let bounds = self
+                .generic_scalar_param()
+                .filter(|param| !param.bounds.is_empty())
+                .map(|param| {
+                    let bounds = &param.bounds;
+                    quote! { #ident: #bounds, }
+                });
+            // Objects are resolved asynchronously as well, with a `Send + Sync` scalar.
+            let thread_safe = self.generic_scalar_param().map(|_| {
+                quote! { + ::std::marker::Send + ::std::marker::Sync }
+            });
+            (
+                quote! { <#( #lifetimes, )* #ident> },
+                quote! {
+                    #ident: ::juniper::ScalarValue #thread_safe + 'static,
+                    #bounds
+                    #( #predicates, )*
+                },
+            )
+        } else if lifetimes.is_empty() {
+            (TokenStream::new(), quote! { #( #predicates, )* })
+        } else {
+            (
+                quote! { <#( #lifetimes ),*> },
+                quote! { #( #predicates, )* },
+            )
+        }
+    }
+
+    /// Returns the generic parameters and where clause predicates of the inherent impls of the
+    /// composite, which can't introduce a generic scalar value type, nor the predicates that may
+    /// mention it.
+    pub fn inherent_impl_generics(&self) -> (TokenStream, TokenStream) {
+        if !self.has_generic_scalar() {
+            return self.impl_generics();
+        }
+        let lifetimes = self.lifetimes().collect::<Vec<_>>();
+        if lifetimes.is_empty() {
+            (TokenStream::new(), TokenStream::new())
+        } else {
+            (quote! { <#( #lifetimes ),*> }, TokenStream::new())
+        }
+    }
+
+    /// Returns the type the composite's traits are implemented for, which is generic over the
+    /// lifetime parameters of the composite.
+    pub fn self_ty(&self) -> TokenStream {
+        let ident = &self.ident;
+        let lifetimes = self
+            .lifetimes()
+            .map(|param| &param.lifetime)
+            .collect::<Vec<_>>();
+        if lifetimes.is_empty() {
+            quote! { #ident }
+        } else {
+            quote! { #ident<#( #lifetimes ),*> }
+        }
+    }
+
+    /// Returns `tokens` with the lifetime parameters of the composite replaced by `'static`, so
+    /// that the types they refer to can be named in constant evaluation, where the parameters are
+    /// out of scope.
+    pub fn to_static(&self, tokens: &impl ToTokens) -> TokenStream {
+        let lifetimes = self
+            .lifetimes()
+            .map(|param| param.lifetime.ident.to_string())
+            .collect::<Vec<_>>();
+        replace_lifetimes(tokens.to_token_stream(), &lifetimes)
+    }
+
+    /// Fails if the composite has lifetime parameters, which only composite objects and
+    /// subscriptions can carry.
+    pub fn reject_lifetimes(&self) -> Result<()> {
+        match self.lifetimes().next() {
+            Some(lifetime) => Err(Error::new(
+                lifetime.span(),
+                "only composite objects and subscriptions can have lifetime parameters",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the interfaces the composite implements.
+    pub fn interfaces(&self) -> impl Iterator<Item = &Type> {
+        self.interfaces
+            .iter()
+            .flat_map(|(_, interfaces)| interfaces.iter())
+    }
+
+    /// Returns `true` if the composite is declared `rename_all = "none"`, which asserts that none
+    /// of its members converts the names of its fields either.
+    fn keeps_names(&self) -> bool {
+        matches!(self.rename_all, Some((_, Case::Unchanged)))
+    }
+
+    /// Returns the members listed in the invocation, leaving out the ones inline fields and
+    /// `federation` add.
+    fn listed_members(&self) -> impl Iterator<Item = &CompositeMember> {
+        let count = self.composables.len()
+            - usize::from(self.inline_fields.is_some())
+            - usize::from(self.federation.is_some());
+        self.composables.iter().take(count)
+    }
+
+    /// Returns the index of the member listed as `fallback`, if any.
+    fn fallback_index(&self) -> Option<usize> {
+        self.composables
+            .iter()
+            .position(|composable| composable.fallback.is_some())
+    }
+
+    /// Returns the identifier of the composable object resolving the inline fields. It's defined
+    /// next to the composite so that its resolvers can be called, which reserves the name there.
+    fn inline_fields_ident(&self) -> Ident {
+        format_ident!("{}Fields", self.ident)
+    }
+
+    /// Fails if the composite has a `rename_all` argument, which would expose the fields of an
+    /// interface under other names than its implementers do, and which unions have no fields for.
+    pub fn reject_rename_all(&self) -> Result<()> {
+        match &self.rename_all {
+            Some((keyword, _)) => Err(Error::new(
+                keyword.span,
+                "only composite objects, subscriptions and input objects can have a `rename_all` \
+                 argument",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns `true` if a `#[derive(...)]` attribute of the invocation derives `trait_name`.
+    fn derives(&self, trait_name: &str) -> bool {
+        self.attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("derive"))
+            .filter_map(|attr| {
+                attr.parse_args_with(Punctuated::<Path, Comma>::parse_terminated)
+                    .ok()
+            })
+            .flatten()
+            .any(|path| {
+                path.segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == trait_name)
+            })
+    }
+
+    fn lifetimes(&self) -> impl Iterator<Item = &LifetimeParam> {
+        self.params.iter().flat_map(|params| &params.lifetimes)
+    }
+
+    /// Returns the builder method call setting the description of the GraphQL type, if it has one.
+    pub fn description(&self) -> Option<TokenStream> {
+        self.description
+            .as_ref()
+            .map(|description| quote! { .description(#description) })
+    }
+
+    /// Fails if the composite is declared `sync`, `sorted` or `federation`, implements
+    /// interfaces or has members resolved with another context, which only composite objects
+    /// support.
+    pub fn reject_object_keywords(&self) -> Result<()> {
+        if let Some(sync) = &self.sync {
+            return Err(Error::new(
+                sync.span,
+                "only composite objects can be declared `sync`",
+            ));
+        }
+        if let Some(sorted) = &self.sorted {
+            return Err(Error::new(
+                sorted.span,
+                "only composite objects can be declared `sorted`",
+            ));
+        }
+        if let Some(federation) = &self.federation {
+            return Err(Error::new(
+                federation.span,
+                "only composite objects can be declared `federation`",
+            ));
+        }
+        if let Some((keyword, _)) = &self.interfaces {
+            return Err(Error::new(
+                keyword.span,
+                "only composite objects can implement interfaces",
+            ));
+        }
+        if let Some(inline_fields) = &self.inline_fields {
+            return Err(Error::new(
+                inline_fields.brace.span.join(),
+                "only composite objects can have inline fields",
+            ));
+        }
+        if let Some((keyword, _)) = &self.map_err {
+            return Err(Error::new(
+                keyword.span,
+                "only composite objects can map the errors of their fields",
+            ));
+        }
+        if let Some((keyword, _)) = &self.info_value {
+            return Err(Error::new(
+                keyword.span,
+                "only composite objects can be given their type info",
+            ));
+        }
+        if let Some(param) = self.generic_scalar_param() {
+            return Err(Error::new(
+                param.ident.span(),
+                "only composite objects can declare their scalar value type as a type parameter",
+            ));
+        }
+        if let Some(fallback) = self
+            .composables
+            .iter()
+            .find_map(|composable| composable.fallback.as_ref())
+        {
+            return Err(Error::new(
+                fallback.span,
+                "only composite objects can have a fallback member",
+            ));
+        }
+        if let Some(gated) = self
+            .composables
+            .iter()
+            .find_map(|composable| composable.gated.as_ref())
+        {
+            return Err(Error::new(
+                gated.span,
+                "only members of composite objects can be gated",
+            ));
+        }
+        if let Some(context) = self
+            .composables
+            .iter()
+            .find_map(|composable| composable.context_as.as_ref())
+        {
+            return Err(Error::new(
+                context.span(),
+                "only members of composite objects can be resolved with another context",
+            ));
+        }
+        Ok(())
+    }
+
+    fn explicit_context(&self) -> Option<Type> {
+        self.param(|param| match param {
+            CompositeParam::Context(ty) => Some(ty),
+            _ => None,
+        })
+    }
+
+    fn explicit_scalar(&self) -> Option<Type> {
+        self.param(|param| match param {
+            CompositeParam::ScalarValue(ty) => Some(ty),
+            _ => None,
+        })
+    }
+
+    fn generic_scalar_param(&self) -> Option<&TypeParam> {
+        self.params
+            .iter()
+            .flat_map(|params| &params.params)
+            .find_map(|param| match param {
+                CompositeParam::GenericScalar(param) => Some(param),
+                _ => None,
+            })
+    }
+
+    fn explicit_info(&self) -> Option<Type> {
+        self.param(|param| match param {
+            CompositeParam::Info(ty) => Some(ty),
+            _ => None,
+        })
+    }
+
+    fn param(&self, f: impl Fn(&CompositeParam) -> Option<&Type>) -> Option<Type> {
+        self.params
+            .iter()
+            .flat_map(|params| &params.params)
+            .find_map(f)
+            .cloned()
+    }
+
+    /// Binds the name of the field being resolved to `exposed_name` if a member is `gated`, whose
+    /// disabled fields are reported under that name rather than the one the member gives them.
+    fn expand_exposed_name(&self) -> Option<TokenStream> {
+        self.composables
+            .iter()
+            .any(|composable| composable.gated.is_some())
+            .then(|| quote! { let exposed_name = field_name; })
+    }
+
+    /// Adds the members inline fields and `federation` imply to the listed ones, and applies the
+    /// arguments of the composite to every member.
+    fn complete_members(&mut self) -> Result<()> {
+        if self.generic_members.is_some() {
+            for member in &mut self.composables {
+                member.add_scalar_placeholder();
+            }
+        }
+        if self.inline_fields.is_some() {
+            let member = inline_fields_member(self)?;
+            self.composables.push(member);
+        }
+        // Before the `federation` member is added, as Apollo Federation names `_service`.
+        if let Some((_, case)) = self.rename_all {
+            for member in &mut self.composables {
+                member.case = case;
+            }
+        }
+        if self.federation.is_some() {
+            let member = federation_member(self);
+            self.composables.push(member);
+        } else if let Some(keyword) = self
+            .composables
+            .iter()
+            .find_map(|composable| composable.resolve_reference.as_ref())
+        {
+            return Err(Error::new(
+                keyword.span,
+                "only members of composite objects declared `federation` can resolve references",
+            ));
+        }
+        if let Some(fallback) = self
+            .composables
+            .iter()
+            .filter_map(|composable| composable.fallback.as_ref())
+            .nth(1)
+        {
+            return Err(Error::new(
+                fallback.span,
+                "a composite object can only have one fallback member",
+            ));
+        }
+        self.reject_generic_scalar_placeholders()?;
+        let scalar = self.const_scalar();
+        for member in &mut self.composables {
+            member.instantiate_scalar(&scalar)?;
+        }
+        Ok(())
+    }
+}
+
+impl Parse for CompositeInput {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let (attrs, description) = parse_description(attrs)?;
+        let vis = input.parse()?;
+        let ident = input.parse()?;
+        let graphql_name = if input.peek(Token![as]) {
+            input.parse::<Token![as]>()?;
+            let name = input.parse::<LitStr>()?;
+            validate_graphql_name(&name)?;
+            Some(name)
+        } else {
+            None
+        };
+        let params = if input.peek(Token![<]) {
+            Some(input.parse::<CompositeParams>()?)
+        } else {
+            None
+        };
+        let mut sync = None;
+        let mut sorted = None;
+        let mut federation = None;
+        let mut generic_members = None;
+        let mut rename_all = None;
+        let mut map_err = None;
+        let mut interfaces = None;
+        loop {
+            if input.peek(kw::sync) {
+                parse_flag(input, &mut sync, "sync")?;
+            } else if input.peek(kw::sorted) {
+                parse_flag(input, &mut sorted, "sorted")?;
+            } else if input.peek(kw::federation) {
+                parse_flag(input, &mut federation, "federation")?;
+            } else if input.peek(kw::generic_members) {
+                parse_flag(input, &mut generic_members, "generic_members")?;
+            } else if input.peek(kw::rename_all) {
+                let (keyword, case) = parse_rename_all(input)?;
+                if rename_all.replace((keyword, case)).is_some() {
+                    return Err(Error::new(keyword.span, "duplicate `rename_all`"));
+                }
+            } else if input.peek(kw::map_err) {
+                let (keyword, path) = parse_map_err(input)?;
+                if map_err.replace((keyword, path)).is_some() {
+                    return Err(Error::new(keyword.span, "duplicate `map_err`"));
+                }
+            } else if input.peek(Token![impl]) {
+                let (keyword, list) = parse_interfaces(input)?;
+                if interfaces.replace((keyword, list)).is_some() {
+                    return Err(Error::new(keyword.span, "duplicate `impl`"));
+                }
+            } else {
+                break;
+            }
+        }
+        let content;
+        let paren = parenthesized!(content in input);
+        let composables = content.parse_terminated(CompositeMember::parse, Comma)?;
+        // A GraphQL object or interface type must have at least one field.
+        if composables.is_empty() {
+            return Err(Error::new(
+                paren.span.join(),
+                "a composite type requires at least one member",
+            ));
+        }
+        let has_info = params
+            .iter()
+            .flat_map(|params| &params.params)
+            .any(|param| matches!(param, CompositeParam::Info(_)));
+        let info_value = parse_info_value(input, has_info)?;
+        let mut composite = Self {
+            attrs,
+            description,
+            vis,
+            ident,
+            graphql_name,
+            params,
+            sync,
+            sorted,
+            federation,
+            generic_members,
+            rename_all,
+            map_err,
+            interfaces,
+            paren,
+            composables,
+            info_value,
+            where_clause: input.parse()?,
+            inline_fields: if input.peek(Brace) {
+                Some(input.parse()?)
+            } else {
+                None
+            },
+            generic_scalar: false,
+        };
+        composite.complete_members()?;
+        Ok(composite)
+    }
+}
+
+/// Parses a flag keyword of a composite such as `sync`, rejecting duplicates.
+fn parse_flag<K: Parse + Spanned>(
+    input: syn::parse::ParseStream,
+    slot: &mut Option<K>,
+    name: &str,
+) -> Result<()> {
+    let keyword = input.parse::<K>()?;
+    let span = keyword.span();
+    if slot.replace(keyword).is_some() {
+        return Err(Error::new(span, format!("duplicate `{name}`")));
+    }
+    Ok(())
+}
+
+/// Parses the `rename_all = "..."` argument of a composite.
+fn parse_rename_all(input: syn::parse::ParseStream) -> Result<(kw::rename_all, Case)> {
+    let keyword = input.parse::<kw::rename_all>()?;
+    input.parse::<Token![=]>()?;
+    let case = input.parse::<LitStr>()?;
+    let case = match case.value().as_str() {
+        "none" => Case::Unchanged,
+        "camelCase" => Case::Camel,
+        "snake_case" => Case::Snake,
+        _ => {
+            return Err(Error::new(
+                case.span(),
+                "expected \"camelCase\", \"snake_case\" or \"none\"",
+            ));
+        }
+    };
+    Ok((keyword, case))
+}
+
+/// Parses the `map_err = path` argument of a composite.
+fn parse_map_err(input: syn::parse::ParseStream) -> Result<(kw::map_err, Path)> {
+    let keyword = input.parse::<kw::map_err>()?;
+    input.parse::<Token![=]>()?;
+    Ok((keyword, input.parse()?))
+}
+
+/// Parses the `info = expr` argument after the members of a composite, if there is one. A struct
+/// literal needs parentheses, since braces after the expression start the inline fields.
+fn parse_info_value(
+    input: syn::parse::ParseStream,
+    has_info: bool,
+) -> Result<Option<(kw::info, Expr)>> {
+    if !input.peek(kw::info) {
+        return Ok(None);
+    }
+    let keyword = input.parse::<kw::info>()?;
+    if !has_info {
+        return Err(Error::new(
+            keyword.span,
+            "`info = ...` requires the type info to be specified with `Info = ...`",
+        ));
+    }
+    input.parse::<Token![=]>()?;
+    Ok(Some((keyword, Expr::parse_without_eager_brace(input)?)))
+}
+
+/// Parses the interfaces listed after `impl` in a composite object.
+fn parse_interfaces(
+    input: syn::parse::ParseStream,
+) -> Result<(Token![impl], Punctuated<Type, Comma>)> {
+    let keyword = input.parse::<Token![impl]>()?;
+    let content;
+    let paren = parenthesized!(content in input);
+    let interfaces = content.parse_terminated(Type::parse, Comma)?;
+    if interfaces.is_empty() {
+        return Err(Error::new(
+            paren.span.join(),
+            "expected at least one interface",
+        ));
+    }
+    Ok((keyword, interfaces))
+}
+
+/// Resolvers of the fields a composite object defines itself, as in
+/// `composite_object!(Query(UserQueries) { fn version() -> &str { "1.0" } })`.
+struct InlineFields {
+    brace: Brace,
+    items: Vec<ImplItem>,
+}
+
+impl Parse for InlineFields {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let content;
+        let brace = braced!(content in input);
+        let mut items = Vec::new();
+        while !content.is_empty() {
+            items.push(content.parse()?);
+        }
+        if items.is_empty() {
+            return Err(Error::new(brace.span.join(), "expected at least one field"));
+        }
+        Ok(Self { brace, items })
+    }
+}
+
+/// Builds the member resolving the inline fields of a composite object, listed after the members
+/// of the invocation. It's an object of juniper's, so it has neither type info nor lifetimes.
+fn inline_fields_member(input: &CompositeInput) -> Result<CompositeMember> {
+    let span = input
+        .inline_fields
+        .as_ref()
+        .map_or_else(Span::call_site, |inline_fields| {
+            inline_fields.brace.span.join()
+        });
+    if input.has_info() {
+        return Err(Error::new(
+            span,
+            "inline fields can't be resolved with type info, compose an object implementing \
+             `GraphQLType` instead",
+        ));
+    }
+    if input.lifetimes().next().is_some() {
+        return Err(Error::new(
+            span,
+            "composite objects with lifetime parameters can't have inline fields",
+        ));
+    }
+    let ident = input.inline_fields_ident();
+    Ok(CompositeMember {
+        cfg: Vec::new(),
+        ty: parse_quote! { #ident },
+        from_context: false,
+        fallback: None,
+        resolve_reference: None,
+        gated: None,
+        context_as: None,
+        prefix: None,
+        renames: Vec::new(),
+        excluded: Vec::new(),
+        only: None,
+        case: Case::default(),
+        scalar_placeholder: None,
+    })
+}
+
+/// Defines the composable object resolving the inline fields of a composite object, with the
+/// context and scalar value type of the composite.
+fn expand_inline_fields(input: &CompositeInput) -> TokenStream {
+    let Some(inline_fields) = &input.inline_fields else {
+        return TokenStream::new();
+    };
+    let vis = &input.vis;
+    let ident = input.inline_fields_ident();
+    let context = input.context();
+    // A generic scalar value type is out of scope, so the resolvers are generic over their own.
+    let scalar = if input.has_generic_scalar() {
+        let ident = input.scalar();
+        quote! { #ident: ::juniper::ScalarValue + ::std::marker::Send + ::std::marker::Sync }
+    } else {
+        input.scalar().to_token_stream()
+    };
+    let doc = format!(" Resolves the inline fields of [`{}`].", input.ident);
+    let items = &inline_fields.items;
+    // The inline fields of a composite declared `rename_all = "none"` keep their names too.
+    let rename_all = input
+        .keeps_names()
+        .then(|| quote! { , rename_all = "none" });
+    quote! {
+        #[doc = #doc]
+        #[derive(::std::default::Default)]
+        #vis struct #ident;
+
+        #[::juniper_compose::composable_object]
+        #[::juniper::graphql_object(context = #context, scalar = #scalar #rename_all)]
+        impl #ident {
+            #( #items )*
+        }
+    }
+}
+
+/// Builds the member adding the fields Apollo Federation requires of the query type of a
+/// subgraph, listed after the members of a `federation` composite.
+fn federation_member(input: &CompositeInput) -> CompositeMember {
+    let context = input.context();
+    let info = input.info();
+    CompositeMember {
+        cfg: Vec::new(),
+        ty: parse_quote! { ::juniper_compose::Federation<#context, #info> },
+        from_context: false,
+        fallback: None,
+        resolve_reference: None,
+        gated: None,
+        context_as: None,
+        prefix: None,
+        renames: Vec::new(),
+        excluded: Vec::new(),
+        only: None,
+        case: Case::default(),
+        scalar_placeholder: None,
+    }
+}
+
+/// Replaces the `_` placeholders in `tokens` with the scalar value type `scalar`.
+fn replace_scalar_placeholder(tokens: TokenStream, scalar: &Type) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Group(group) => {
+                let mut replaced = Group::new(
+                    group.delimiter(),
+                    replace_scalar_placeholder(group.stream(), scalar),
+                );
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced).into_token_stream()
+            }
+            TokenTree::Ident(ident) if ident == "_" => scalar.to_token_stream(),
+            token => token.into_token_stream(),
+        })
+        .collect()
+}
+
+/// Replaces the lifetimes named `lifetimes` in `tokens` with `'static`.
+fn replace_lifetimes(tokens: TokenStream, lifetimes: &[String]) -> TokenStream {
+    let mut after_apostrophe = false;
+    tokens
+        .into_iter()
+        .map(|token| {
+            let token = match token {
+                TokenTree::Group(group) => {
+                    let mut replaced = Group::new(
+                        group.delimiter(),
+                        replace_lifetimes(group.stream(), lifetimes),
+                    );
+                    replaced.set_span(group.span());
+                    TokenTree::Group(replaced)
+                }
+                TokenTree::Ident(ident)
+                    if after_apostrophe && lifetimes.contains(&ident.to_string()) =>
+                {
+                    TokenTree::Ident(Ident::new("static", ident.span()))
+                }
+                token => token,
+            };
+            after_apostrophe = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '\'');
+            token
+        })
+        .collect()
+}
+
+/// Fails unless `name` is a GraphQL name that isn't reserved for introspection.
+fn validate_graphql_name(name: &LitStr) -> Result<()> {
+    let value = name.value();
+    let mut chars = value.chars();
+    let is_valid = chars
+        .next()
+        .is_some_and(|first| first == '_' || first.is_ascii_alphabetic())
+        && chars.all(|char| char == '_' || char.is_ascii_alphanumeric());
+    if !is_valid {
+        return Err(Error::new(
+            name.span(),
+            format!("`{value}` is not a valid GraphQL name"),
+        ));
+    }
+    if value.starts_with("__") {
+        return Err(Error::new(
+            name.span(),
+            format!("`{value}` is reserved for GraphQL introspection"),
+        ));
+    }
+    Ok(())
+}
+
+/// Splits the attributes of the invocation into the ones the generated type carries and the
+/// description of the GraphQL type, which `#[graphql(description = "...")]` takes over the doc
+/// comments. Doc comments are joined into a description the way juniper joins them.
+fn parse_description(attrs: Vec<Attribute>) -> Result<(Vec<Attribute>, Option<LitStr>)> {
+    let mut type_attrs = Vec::new();
+    let mut lines = Vec::new();
+    let mut description = None;
+    for attr in attrs {
+        if attr.path().is_ident("doc") {
+            if let Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(line),
+                        ..
+                    }),
+                ..
+            }) = &attr.meta
+            {
+                lines.push(line.clone());
+            }
+            type_attrs.push(attr);
+        } else if attr.path().is_ident("graphql") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("description") {
+                    description = Some(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `description`"))
+                }
+            })?;
+        } else if attr.path().is_ident("cfg") {
+            // The impls of the generated type would be left behind.
+            return Err(Error::new(
+                attr.span(),
+                "put `#[cfg(...)]` on the macro invocation instead",
+            ));
+        } else {
+            type_attrs.push(attr);
+        }
+    }
+    let description = description.or_else(|| {
+        let span = lines.first()?.span();
+        let last_index = lines.len() - 1;
+        let joined = lines.iter().map(LitStr::value).enumerate().fold(
+            String::new(),
+            |mut joined, (index, line)| {
+                let line = line.trim_end();
+                let line = line.strip_prefix(' ').unwrap_or(line);
+                if index == last_index {
+                    joined.push_str(line);
+                } else if line.ends_with('\\') {
+                    joined.push_str(line.trim_end_matches('\\'));
+                    joined.push(' ');
+                } else {
+                    joined.push_str(line);
+                    joined.push('\n');
+                }
+                joined
+            },
+        );
+        Some(LitStr::new(&joined, span))
+    });
+    Ok((
+        type_attrs,
+        description.filter(|description| !description.value().is_empty()),
+    ))
+}
+
+struct CompositeParams {
+    #[allow(dead_code)]
+    left_angle_bracket: Token![<],
+    /// Lifetime parameters of the composite, which come first.
+    lifetimes: Vec<LifetimeParam>,
+    params: Punctuated<CompositeParam, Comma>,
+    #[allow(dead_code)]
+    right_angle_bracket: Token![>],
+}
+
+impl Parse for CompositeParams {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let left_angle_bracket = input.parse()?;
+        let mut lifetimes = Vec::new();
+        let mut params = Punctuated::<CompositeParam, Comma>::new();
+        while !input.peek(Token![>]) {
+            if input.peek(Lifetime) {
+                let lifetime = input.parse::<LifetimeParam>()?;
+                if !params.is_empty() {
+                    return Err(Error::new(
+                        lifetime.span(),
+                        "lifetime parameters must be declared before the other parameters",
+                    ));
+                }
+                lifetimes.push(lifetime);
+            } else {
+                let param = input.parse::<CompositeParam>()?;
+                if params
+                    .iter()
+                    .any(|existing| existing.ident() == param.ident())
+                {
+                    return Err(Error::new(
+                        input.span(),
+                        format!("duplicate `{}` parameter", param.ident()),
+                    ));
+                }
+                params.push(param);
+            }
+            if input.peek(Token![>]) {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+        let right_angle_bracket = input.parse()?;
+        Ok(Self {
+            left_angle_bracket,
+            lifetimes,
+            params,
+            right_angle_bracket,
+        })
+    }
+}
+
+enum CompositeParam {
+    Context(Type),
+    Info(Type),
+    ScalarValue(Type),
+    /// `S: ScalarValue`, the type parameter the composite is generic over as its scalar value
+    /// type.
+    GenericScalar(TypeParam),
+}
+
+impl CompositeParam {
+    fn ident(&self) -> &'static str {
+        match self {
+            Self::Context(_) => "Context",
+            Self::Info(_) => "Info",
+            Self::ScalarValue(_) | Self::GenericScalar(_) => "ScalarValue",
+        }
+    }
+}
+
+impl Parse for CompositeParam {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        if input.peek2(Token![:]) {
+            let param = input.parse::<TypeParam>()?;
+            if !param.bounds.iter().any(|bound| match bound {
+                TypeParamBound::Trait(bound) => bound
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == "ScalarValue"),
+                _ => false,
+            }) {
+                return Err(Error::new(
+                    param.ident.span(),
+                    "the scalar value type parameter must be bound by `ScalarValue`",
+                ));
+            }
+            return Ok(Self::GenericScalar(param));
+        }
+        let ident = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+        if ident == "Context" {
+            Ok(Self::Context(input.parse()?))
+        } else if ident == "Info" {
+            Ok(Self::Info(input.parse()?))
+        } else if ident == "ScalarValue" {
+            Ok(Self::ScalarValue(input.parse()?))
+        } else {
+            Err(Error::new(
+                ident.span(),
+                "expected `Context`, `Info`, `ScalarValue` or `S: ScalarValue`",
+            ))
+        }
+    }
+}
+
+/// A composable type listed in a composite, along with how the composite exposes its fields.
+pub struct CompositeMember {
+    /// `#[cfg(...)]` attributes of the member, which every item and expression referring to it
+    /// carries.
+    pub cfg: Vec<Attribute>,
+    pub ty: Type,
+    from_context: bool,
+    /// `fallback` keyword, which makes the member resolve the fields no other member exposes.
+    fallback: Option<kw::fallback>,
+    /// `resolve_reference` keyword, which makes the member resolve the entities of a
+    /// `federation` composite with `juniper_compose::ResolveReference`.
+    resolve_reference: Option<kw::resolve_reference>,
+    /// `gated` keyword, which makes the composite ask the member with `juniper_compose::FieldGate`
+    /// whether each of its fields is enabled before resolving it.
+    gated: Option<kw::gated>,
+    /// Context given with `as Context`, which the member is resolved with, converted from the
+    /// context of the composite with `juniper::FromContext`.
+    context_as: Option<Type>,
+    prefix: Option<LitStr>,
+    renames: Vec<FieldRename>,
+    /// Fields listed in `- (...)`, which the composite doesn't expose.
+    excluded: Vec<LitStr>,
+    /// Fields listed in `only (...)`, which are the only ones the composite exposes.
+    only: Option<Vec<LitStr>>,
+    /// Case of the exposed names, from the `rename_all` argument of the composite.
+    case: Case,
+    /// Type of the member as written, if it refers to the scalar value type of the composite
+    /// with `_`.
+    scalar_placeholder: Option<Type>,
+}
+
+/// Case given with `rename_all = "..."`, mirroring `juniper_compose::member::Case`.
+#[derive(Clone, Copy, Default)]
+enum Case {
+    #[default]
+    Unchanged,
+    Camel,
+    Snake,
+}
+
+impl ToTokens for Case {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let variant = match self {
+            Self::Unchanged => quote! { Unchanged },
+            Self::Camel => quote! { Camel },
+            Self::Snake => quote! { Snake },
+        };
+        tokens.extend(quote! { ::juniper_compose::member::Case::#variant });
+    }
+}
+
+mod kw {
+    syn::custom_keyword!(fallback);
+    syn::custom_keyword!(federation);
+    syn::custom_keyword!(from_context);
+    syn::custom_keyword!(gated);
+    syn::custom_keyword!(generic_members);
+    syn::custom_keyword!(info);
+    syn::custom_keyword!(map_err);
+    syn::custom_keyword!(only);
+    syn::custom_keyword!(prefix);
+    syn::custom_keyword!(rename_all);
+    syn::custom_keyword!(resolve_reference);
+    syn::custom_keyword!(sorted);
+    syn::custom_keyword!(sync);
+}
+
+/// `field: exposed_name` or `field: (alias, ..., exposed_name)` entry of a member's `{ ... }`
+/// block.
+struct FieldRename {
+    field: LitStr,
+    exposed: LitStr,
+    /// Deprecated names the field is exposed under as well, listed before the exposed name.
+    aliases: Vec<FieldAlias>,
+}
+
+/// `alias` or `alias = "reason"` entry of the names a field is exposed under.
+struct FieldAlias {
+    name: LitStr,
+    /// Reason the alias is deprecated for, if it isn't the generated one.
+    reason: Option<LitStr>,
+}
+
+impl CompositeMember {
+    /// Adds `<_>` to the type of the member if it is a path without generic arguments, for a
+    /// composite declared `generic_members`. A member written `Member<>` is left as it is.
+    fn add_scalar_placeholder(&mut self) {
+        let Type::Path(type_path) = &mut self.ty else {
+            return;
+        };
+        let Some(segment) = type_path.path.segments.last_mut() else {
+            return;
+        };
+        if type_path.qself.is_none() && segment.arguments.is_none() {
+            segment.arguments = PathArguments::AngleBracketed(parse_quote! { <_> });
+            self.scalar_placeholder = Some(self.ty.clone());
+        }
+    }
+
+    /// Replaces the `_` placeholders in the type of the member with the scalar value type
+    /// `scalar`.
+    pub fn instantiate_scalar(&mut self, scalar: &Type) -> Result<()> {
+        if let Some(written) = &self.scalar_placeholder {
+            self.ty = syn::parse2(replace_scalar_placeholder(
+                written.to_token_stream(),
+                scalar,
+            ))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the name of the type of this member as it is listed, which names it in diagnostics
+    /// and in `field_origin`.
+    fn type_name_lit(&self) -> LitStr {
+        type_name_lit(self.scalar_placeholder.as_ref().unwrap_or(&self.ty))
+    }
+
+    /// Builds the `juniper_compose::member::Member` describing this member, listing
+    /// `fields` as its fields.
+    pub fn descriptor(&self, fields: &TokenStream) -> TokenStream {
+        self.descriptor_excluding(fields, &[])
+    }
+
+    /// Builds the `juniper_compose::member::Member` describing this member like
+    /// [`Self::descriptor`], leaving out the fields listed in `excluded` as well.
+    fn descriptor_excluding(&self, fields: &TokenStream, excluded: &[&LitStr]) -> TokenStream {
+        let type_name = self.type_name_lit();
+        let renames = self
+            .renames
+            .iter()
+            .map(|FieldRename { field, exposed, .. }| {
+                quote! { (#field, #exposed) }
+            });
+        let aliases = self.renames.iter().flat_map(|rename| {
+            let field = &rename.field;
+            rename
+                .aliases
+                .iter()
+                .map(move |FieldAlias { name, reason }| {
+                    let reason = reason.as_ref().map_or_else(
+                        || quote! { ::std::option::Option::None },
+                        |reason| quote! { ::std::option::Option::Some(#reason) },
+                    );
+                    quote! { (#field, #name, #reason) }
+                })
+        });
+        let prefix = self
+            .prefix
+            .clone()
+            .unwrap_or_else(|| LitStr::new("", Span::call_site()));
+        let excluded = self.excluded.iter().chain(excluded.iter().copied());
+        let only = self.only.as_ref().map_or_else(
+            || quote! { ::std::option::Option::None },
+            |only| quote! { ::std::option::Option::Some(&[#( #only ),*]) },
+        );
+        let case = self.case;
+        let fallback = self.fallback.is_some();
+        quote! {
+            ::juniper_compose::member::Member {
+                type_name: #type_name,
+                fields: #fields,
+                renames: &[#( #renames ),*],
+                aliases: &[#( #aliases ),*],
+                prefix: #prefix,
+                excluded: &[#( #excluded ),*],
+                only: #only,
+                case: #case,
+                fallback: #fallback,
+            }
+        }
+    }
+
+    /// Builds the expression constructing the member before resolving one of its fields. It may
+    /// refer to the `executor` the field is resolved with, and only borrows its context, which
+    /// is converted to the context of the member from `context`, the one of the composite.
+    pub fn constructor(&self, scalar: &Type, context: &Type) -> TokenStream {
+        let ty = &self.ty;
+        if self.from_context {
+            let member_context = self.context(context);
+            quote! {
+                <#ty as ::juniper_compose::ComposableFromContext<#scalar>>::from_context(
+                    #member_context,
+                )
+            }
+        } else {
+            quote! { <#ty as ::std::default::Default>::default() }
+        }
+    }
+
+    /// Builds the expression borrowing the context of the member from the `executor` of the
+    /// composite, whose context is `context`.
+    fn context(&self, context: &Type) -> TokenStream {
+        if let Some(member_context) = &self.context_as {
+            quote! {
+                <#member_context as ::juniper::FromContext<#context>>::from(executor.context())
+            }
+        } else {
+            quote! { executor.context() }
+        }
+    }
+
+    /// Wraps `call`, resolving the field `field_name` of the member, in the check of a `gated`
+    /// member that the field is enabled in the member's context, evaluating to `disabled` if it
+    /// isn't.
+    fn gate(
+        &self,
+        scalar: &Type,
+        context: &Type,
+        call: TokenStream,
+        disabled: &TokenStream,
+    ) -> TokenStream {
+        if self.gated.is_none() {
+            return call;
+        }
+        let ty = &self.ty;
+        let member_context = self.context(context);
+        quote! {
+            if <#ty as ::juniper_compose::FieldGate<#scalar>>::is_field_enabled(
+                #member_context,
+                field_name,
+            ) {
+                #call
+            } else {
+                #disabled
+            }
+        }
+    }
+
+    /// Builds the expression of the executor resolving the fields of the member synchronously,
+    /// borrowing `executor` or an executor of its converted context.
+    fn executor(&self, context: &Type) -> TokenStream {
+        if self.context_as.is_some() {
+            let member_context = self.context(context);
+            quote! { &executor.replaced_context(#member_context) }
+        } else {
+            quote! { executor }
+        }
+    }
+
+    /// Returns `true` if the member is listed without `from_context`, `fallback`, `as`, `prefix`,
+    /// renames or field selection.
+    pub fn is_plain(&self) -> bool {
+        !self.from_context
+            && self.fallback.is_none()
+            && self.context_as.is_none()
+            && self.prefix.is_none()
+            && self.renames.is_empty()
+            && self.excluded.is_empty()
+            && self.only.is_none()
+    }
+
+    /// Returns `true` if the member is listed with at most a `prefix` and renames without
+    /// aliases, and isn't conditionally compiled.
+    pub fn only_renames(&self) -> bool {
+        self.cfg.is_empty()
+            && !self.from_context
+            && self.fallback.is_none()
+            && self.context_as.is_none()
+            && self.excluded.is_empty()
+            && self.only.is_none()
+            && self.renames.iter().all(|rename| rename.aliases.is_empty())
+    }
+
+    /// Returns the fields given a name of their own in the `{ ... }` block of the member.
+    fn renamed_fields(&self) -> Vec<&LitStr> {
+        self.renames.iter().map(|rename| &rename.field).collect()
+    }
+
+    fn has_renames(&self) -> bool {
+        !self.renames.is_empty()
+    }
+
+    fn has_excluded(&self) -> bool {
+        !self.excluded.is_empty()
+    }
+
+    fn has_only(&self) -> bool {
+        self.only.is_some()
+    }
+}
+
+impl Parse for CompositeMember {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        // Doc comments document the member list where it is written, not the schema.
+        let mut cfg = input.call(Attribute::parse_outer)?;
+        cfg.retain(|attr| !attr.path().is_ident("doc"));
+        if let Some(attr) = cfg.iter().find(|attr| !attr.path().is_ident("cfg")) {
+            return Err(Error::new_spanned(
+                attr,
+                "only `cfg` attributes and doc comments are supported on members",
+            ));
+        }
+        let mut from_context = false;
+        let mut fallback = None;
+        let mut resolve_reference = None;
+        let mut gated = None;
+        let mut prefix = None;
+        loop {
+            if input.peek(kw::from_context) && input.peek2(Ident::peek_any) {
+                let keyword = input.parse::<kw::from_context>()?;
+                if from_context {
+                    return Err(Error::new(keyword.span, "duplicate `from_context`"));
+                }
+                from_context = true;
+            } else if input.peek(kw::fallback) && input.peek2(Ident::peek_any) {
+                let keyword = input.parse::<kw::fallback>()?;
+                if fallback.replace(keyword).is_some() {
+                    return Err(Error::new(keyword.span, "duplicate `fallback`"));
+                }
+            } else if input.peek(kw::resolve_reference) && input.peek2(Ident::peek_any) {
+                let keyword = input.parse::<kw::resolve_reference>()?;
+                if resolve_reference.replace(keyword).is_some() {
+                    return Err(Error::new(keyword.span, "duplicate `resolve_reference`"));
+                }
+            } else if input.peek(kw::gated) && input.peek2(Ident::peek_any) {
+                let keyword = input.parse::<kw::gated>()?;
+                if gated.replace(keyword).is_some() {
+                    return Err(Error::new(keyword.span, "duplicate `gated`"));
+                }
+            } else if input.peek(kw::prefix)
+                && (input.peek2(Ident::peek_any) || input.peek2(LitStr))
+            {
+                let keyword = input.parse::<kw::prefix>()?;
+                if prefix.is_some() {
+                    return Err(Error::new(keyword.span, "duplicate `prefix`"));
+                }
+                prefix = Some(parse_field_name(input)?);
+            } else {
+                break;
+            }
+        }
+        let ty = input.parse::<Type>()?;
+        let context_as = if input.peek(Token![as]) {
+            input.parse::<Token![as]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        let renames = if input.peek(syn::token::Brace) {
+            parse_renames(input)?
+        } else {
+            Vec::new()
+        };
+        let mut excluded = Vec::new();
+        let mut only = None;
+        if input.peek(Token![-]) {
+            input.parse::<Token![-]>()?;
+            excluded = parse_field_list(input, "excluded")?;
+        } else if input.peek(kw::only) && input.peek2(Paren) {
+            input.parse::<kw::only>()?;
+            only = Some(parse_field_list(input, "selected")?);
+        }
+        if input.peek(Token![-]) || input.peek(kw::only) {
+            return Err(Error::new(
+                input.span(),
+                "fields can either be excluded with `- (...)` or selected with `only (...)`",
+            ));
+        }
+        Ok(Self {
+            cfg,
+            from_context,
+            fallback,
+            resolve_reference,
+            gated,
+            context_as,
+            prefix,
+            renames,
+            excluded,
+            only,
+            case: Case::default(),
+            scalar_placeholder: has_placeholder(ty.to_token_stream()).then(|| ty.clone()),
+            ty,
+        })
+    }
+}
+
+/// Parses the `{ field: exposed_name, ... }` block renaming the fields of a member.
+fn parse_renames(input: syn::parse::ParseStream) -> Result<Vec<FieldRename>> {
+    let content;
+    braced!(content in input);
+    let mut renames = Vec::<FieldRename>::new();
+    while !content.is_empty() {
+        let field = parse_field_name(&content)?;
+        content.parse::<Token![:]>()?;
+        let (exposed, aliases) = if content.peek(Paren) {
+            parse_aliases(&content, &field)?
+        } else {
+            (parse_field_name(&content)?, Vec::new())
+        };
+        if renames
+            .iter()
+            .any(|rename| rename.field.value() == field.value())
+        {
+            return Err(Error::new(
+                field.span(),
+                format!("field `{}` is renamed more than once", field.value()),
+            ));
+        }
+        renames.push(FieldRename {
+            field,
+            exposed,
+            aliases,
+        });
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+    Ok(renames)
+}
+
+/// Parses the `(alias, ..., exposed_name)` list of the names `field` is exposed under. Each alias
+/// can be followed by `= "reason"`, the reason it is deprecated for.
+fn parse_aliases(
+    input: syn::parse::ParseStream,
+    field: &LitStr,
+) -> Result<(LitStr, Vec<FieldAlias>)> {
+    let content;
+    parenthesized!(content in input);
+    let mut names = Vec::<FieldAlias>::new();
+    while !content.is_empty() {
+        let name = parse_field_name(&content)?;
+        if names
+            .iter()
+            .any(|existing| existing.name.value() == name.value())
+        {
+            return Err(Error::new(
+                name.span(),
+                format!("field `{}` is aliased more than once", name.value()),
+            ));
+        }
+        let reason = if content.peek(Token![=]) {
+            content.parse::<Token![=]>()?;
+            Some(content.parse::<LitStr>()?)
+        } else {
+            None
+        };
+        names.push(FieldAlias { name, reason });
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+    if names.len() < 2 {
+        return Err(Error::new(
+            field.span(),
+            "list the deprecated aliases of the field followed by the name it is exposed under",
+        ));
+    }
+    let FieldAlias { name, reason } = names.pop().expect("names aren't empty");
+    if let Some(reason) = reason {
+        return Err(Error::new(
+            reason.span(),
+            "the name a field is exposed under isn't deprecated, only its aliases have a \
+             deprecation reason",
+        ));
+    }
+    Ok((name, names))
+}
+
+/// Returns `true` if `tokens` contain a `_` placeholder for the scalar value type.
+fn has_placeholder(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Group(group) => has_placeholder(group.stream()),
+        TokenTree::Ident(ident) => ident == "_",
+        _ => false,
+    })
+}
+
+/// Parses a parenthesized list of distinct field names, which are `listed` as described by the
+/// error message for a name listed twice.
+fn parse_field_list(input: syn::parse::ParseStream, listed: &str) -> Result<Vec<LitStr>> {
+    let content;
+    parenthesized!(content in input);
+    let mut fields = Vec::<LitStr>::new();
+    while !content.is_empty() {
+        let field = parse_field_name(&content)?;
+        if fields
+            .iter()
+            .any(|existing| existing.value() == field.value())
+        {
+            return Err(Error::new(
+                field.span(),
+                format!("field `{}` is {listed} more than once", field.value()),
+            ));
+        }
+        fields.push(field);
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+    Ok(fields)
+}
+
+/// Parses a GraphQL field name (or field name prefix), given either as a Rust identifier
+/// (converted to camelCase, like juniper does for resolver names) or verbatim as a string
+/// literal.
+fn parse_field_name(input: syn::parse::ParseStream) -> Result<LitStr> {
+    if input.peek(LitStr) {
+        input.parse()
+    } else {
+        let ident = input.call(Ident::parse_any)?;
+        Ok(LitStr::new(
+            &to_camel_case(&ident.unraw().to_string()),
+            ident.span(),
+        ))
+    }
+}
+
+pub fn expand_composite_object(input: &CompositeInput) -> TokenStream {
+    let assert_composable = expand_assert_composable(input, ComposableKind::Object);
+    let definition = expand_definition(input);
+    let impl_debug = expand_impl_debug(input, ComposableKind::Object);
+    let impl_graphql_type = expand_impl_graphql_type(input, ComposableKind::Object, &[]);
+    let impl_graphql_value = expand_impl_graphql_value(input);
+    let impl_graphql_value_async = expand_impl_graphql_value_async(input);
+    let impl_marker_traits = expand_impl_marker_traits(input);
+    let impl_composable = expand_impl_composable(input, ComposableKind::Object);
+    let assert_disjoint = expand_assert_disjoint(input, ComposableKind::Object);
+    let impl_field_origin = expand_impl_field_origin(input, ComposableKind::Object);
+    let impl_field_count = expand_impl_field_count(input, ComposableKind::Object);
+    let assert_has_fields = expand_assert_has_fields(input, ComposableKind::Object);
+    let assert_context = expand_assert_context(input);
+    let assert_sync = expand_assert_sync(input);
+    let assert_unconverted = expand_assert_unconverted(input);
+    let assert_interfaces = expand_assert_interfaces(input);
+    let impl_members = expand_impl_members(input);
+    let impl_resolve_entities = expand_impl_resolve_entities(input);
+    let impl_info = expand_impl_info(input);
+    let register = expand_register(input);
+    let impl_build_schema = expand_impl_build_schema(input);
+    let inline_fields = expand_inline_fields(input);
+    quote! {
+        #assert_composable
+        #definition
+        #impl_debug
+        #inline_fields
+        #impl_members
+        #impl_resolve_entities
+        #impl_info
+        #impl_build_schema
+        #impl_graphql_type
+        #impl_graphql_value
+        #impl_graphql_value_async
+        #impl_marker_traits
+        #impl_composable
+        #impl_field_origin
+        #impl_field_count
+        #assert_has_fields
+        #assert_disjoint
+        #assert_context
+        #assert_sync
+        #assert_unconverted
+        #assert_interfaces
+        #register
+    }
+}
+
+/// Implements the marker and reflection traits juniper requires of the implementers of
+/// interfaces. The composite implements the interfaces listed in `impl (...)`.
+fn expand_impl_marker_traits(input: &CompositeInput) -> TokenStream {
+    let self_ty = input.self_ty();
+    let name_lit = input.name_lit();
+    let scalar = input.scalar();
+    let (impl_generics, bounds) = input.impl_generics();
+    let composable_trait = ComposableKind::Object.trait_path();
+    let interfaces = input.interfaces();
+    quote! {
+        impl #impl_generics ::juniper::marker::IsOutputType<#scalar> for #self_ty
+        where
+            #bounds
+        {
+            fn mark() {}
+        }
+
+        impl #impl_generics ::juniper::marker::GraphQLObject<#scalar> for #self_ty
+        where
+            #bounds
+        {
+            fn mark() {}
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::BaseType<#scalar> for #self_ty
+        where
+            #bounds
+        {
+            const NAME: ::juniper::macros::reflect::Type = #name_lit;
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::BaseSubTypes<#scalar> for #self_ty
+        where
+            #bounds
+        {
+            const NAMES: ::juniper::macros::reflect::Types = &[#name_lit];
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::WrappedType<#scalar> for #self_ty
+        where
+            #bounds
+        {
+            const VALUE: ::juniper::macros::reflect::WrappedValue = 1;
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::Fields<#scalar> for #self_ty
+        where
+            #bounds
+        {
+            const NAMES: ::juniper::macros::reflect::Names =
+                <Self as #composable_trait<#scalar>>::FIELDS;
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::Implements<#scalar> for #self_ty
+        where
+            #bounds
+        {
+            const NAMES: ::juniper::macros::reflect::Types = &[
+                #( <#interfaces as ::juniper::macros::reflect::BaseType<#scalar>>::NAME ),*
+            ];
+        }
+    }
+}
+
+/// Asserts at compile time that every interface the composite implements lists it as an
+/// implementer, and that the composite exposes every field of the interface.
+fn expand_assert_interfaces(input: &CompositeInput) -> TokenStream {
+    let self_ty = input.to_static(&input.self_ty());
+    let name_lit = input.name_lit();
+    let scalar = input.const_scalar();
+    let composable_trait = ComposableKind::Object.trait_path();
+    let interface_trait = ComposableKind::Interface.trait_path();
+    let assertions = input.interfaces().map(|interface| {
+        let interface = input.to_static(interface);
+        quote_spanned! {interface.span()=>
+            ::juniper::assert_implemented_for!(#scalar, #self_ty, #interface);
+            const _: () = ::juniper_compose::check::assert_interface_fields(
+                #name_lit,
+                <#self_ty as #composable_trait<#scalar>>::FIELDS,
+                <#self_ty as #composable_trait<#scalar>>::FIELD_TYPES,
+                <#interface as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
+                <#interface as #interface_trait<#scalar>>::FIELDS,
+                <#interface as #interface_trait<#scalar>>::FIELD_TYPES,
+            );
+        }
+    });
+    quote! { #( #assertions )* }
+}
+
+/// Asserts at compile time that the composite exposes at least one field, which members without
+/// fields, or with all of theirs excluded, don't contribute.
+pub fn expand_assert_has_fields(input: &CompositeInput, kind: ComposableKind) -> TokenStream {
+    let self_ty = input.to_static(&input.self_ty());
+    let name_lit = input.name_lit();
+    let scalar = input.const_scalar();
+    let composable_trait = kind.trait_path();
+    let dynamic_fields = if let ComposableKind::Object = kind {
+        quote! { <#self_ty as #composable_trait<#scalar>>::DYNAMIC_FIELDS }
+    } else {
+        quote! { false }
+    };
+    quote_spanned! {input.ident.span()=>
+        const _: () = ::juniper_compose::check::assert_has_fields(
+            #name_lit,
+            <#self_ty as #composable_trait<#scalar>>::FIELDS,
+            #dynamic_fields,
+        );
+    }
+}
+
+/// Defines the struct of a composite object or subscription, which holds nothing but its lifetime
+/// parameters. It derives `Default`, unless the invocation does already.
+pub fn expand_definition(input: &CompositeInput) -> TokenStream {
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+    let name = &input.ident;
+    let derive_default =
+        (!input.derives("Default")).then(|| quote! { #[derive(::std::default::Default)] });
+    let lifetimes = input.lifetimes().collect::<Vec<_>>();
+    if lifetimes.is_empty() {
+        return quote! {
+            #( #attrs )*
+            #derive_default
+            #vis struct #name;
+        };
+    }
+    let references = lifetimes.iter().map(|param| {
+        let lifetime = &param.lifetime;
+        quote! { &#lifetime () }
+    });
+    quote! {
+        #( #attrs )*
+        #derive_default
+        #vis struct #name<#( #lifetimes ),*>(::std::marker::PhantomData<(#( #references, )*)>);
+    }
+}
+
+/// Implements `Debug` for the composite, rendering it as a struct named after its GraphQL type
+/// with the `fields()` it exposes, unless the invocation derives `Debug` itself. A composite
+/// generic over its scalar value type lists the fields it has for a concrete one.
+pub fn expand_impl_debug(input: &CompositeInput, kind: ComposableKind) -> TokenStream {
+    if input.derives("Debug") {
+        return TokenStream::new();
+    }
+    let self_ty = input.self_ty();
+    let name_lit = input.name_lit();
+    let const_scalar = input.const_scalar();
+    let composable_trait = kind.trait_path();
+    let (impl_generics, bounds) = input.inherent_impl_generics();
+    quote! {
+        impl #impl_generics ::std::fmt::Debug for #self_ty
+        where
+            #bounds
+        {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.debug_struct(#name_lit)
+                    .field("fields", &<Self as #composable_trait<#const_scalar>>::fields())
+                    .finish()
+            }
+        }
+    }
+}
+
+/// Implements `members()` on a composite object, which constructs its members constructed with
+/// `Default`, so that their resolvers can be called without executing a query. Members
+/// constructed from the context, and conditionally compiled ones, whose presence the type of the
+/// tuple can't depend on, are left out.
+fn expand_impl_members(input: &CompositeInput) -> TokenStream {
+    let members = input
+        .listed_members()
+        .filter(|composable| !composable.from_context && composable.cfg.is_empty())
+        .map(|composable| &composable.ty)
+        .collect::<Vec<_>>();
+    if members.is_empty() {
+        return TokenStream::new();
+    }
+    let vis = &input.vis;
+    let self_ty = input.self_ty();
+    let (impl_generics, bounds) = input.inherent_impl_generics();
+    quote! {
+        impl #impl_generics #self_ty
+        where
+            #bounds
+        {
+            /// Returns the composed objects constructed with `Default`, in the order they are
+            /// listed, e.g. to test their resolvers directly.
+            #[must_use]
+            #vis fn members() -> (#( #members, )*) {
+                (#( <#members as ::std::default::Default>::default(), )*)
+            }
+        }
+    }
+}
+
+/// Implements `juniper_compose::ResolveEntities` on a `federation` composite object, trying the
+/// members listed as `resolve_reference` in turn. The entity type is the one of the first member
+/// that isn't conditionally compiled, which the others must resolve references to as well.
+fn expand_impl_resolve_entities(input: &CompositeInput) -> TokenStream {
+    let members = input
+        .listed_members()
+        .filter(|composable| composable.resolve_reference.is_some())
+        .collect::<Vec<_>>();
+    let Some(first) = members
+        .iter()
+        .find(|composable| composable.cfg.is_empty())
+        .or_else(|| members.first())
+    else {
+        return TokenStream::new();
+    };
+    let self_ty = input.self_ty();
+    let context = input.context();
+    let scalar = input.scalar();
+    let (impl_generics, bounds) = input.impl_generics();
+    let entity = &first.ty;
+    let resolvers = members.iter().map(|composable| {
+        let cfg = &composable.cfg;
+        let ty = &composable.ty;
+        let member_context = composable.context_as.as_ref().map_or_else(
+            || quote! { context },
+            |member_context| {
+                quote! { <#member_context as ::juniper::FromContext<#context>>::from(context) }
+            },
+        );
+        quote! {
+            #( #cfg )*
+            let entity = entity.or_else(|| {
+                <#ty as ::juniper_compose::ResolveReference<#scalar>>::resolve_reference(
+                    #member_context,
+                    representation,
+                )
+            });
+        }
+    });
+    quote! {
+        impl #impl_generics ::juniper_compose::ResolveEntities<#scalar> for #self_ty
+        where
+            #bounds
+        {
+            type Entity = <#entity as ::juniper_compose::ResolveReference<#scalar>>::Entity;
+
+            fn resolve_entity(
+                context: &#context,
+                representation: &::juniper_compose::Representation<#scalar>,
+            ) -> ::std::option::Option<Self::Entity> {
+                let entity: ::std::option::Option<Self::Entity> = ::std::option::Option::None;
+                #( #resolvers )*
+                entity
+            }
+        }
+    }
+}
+
+/// Defines the inherent `info` function of a composite object given its type info with
+/// `info = expr`, which evaluates `expr` every time it is called.
+fn expand_impl_info(input: &CompositeInput) -> TokenStream {
+    let Some((_, expr)) = &input.info_value else {
+        return TokenStream::new();
+    };
+    let vis = &input.vis;
+    let self_ty = input.self_ty();
+    let info = input.info();
+    let (impl_generics, bounds) = input.inherent_impl_generics();
+    quote! {
+        impl #impl_generics #self_ty
+        where
+            #bounds
+        {
+            /// Returns the type info to build a schema of this object type with, as given to
+            /// `composite_object!` with `info = ...`. juniper passes it to the meta type and the
+            /// resolvers of every member.
+            #[must_use]
+            #vis fn info() -> #info {
+                #expr
+            }
+        }
+    }
+}
+
+/// Registers the composite object in `juniper_compose::registry` with the `inventory` feature,
+/// naming its listed members by the GraphQL names of their types.
+fn expand_register(input: &CompositeInput) -> TokenStream {
+    if !cfg!(feature = "inventory") {
+        return TokenStream::new();
+    }
+    let name_lit = input.name_lit();
+    let ident = &input.ident;
+    let scalar = input.const_scalar();
+    let members = input.listed_members().map(|composable| {
+        let cfg = &composable.cfg;
+        let ty = input.to_static(&composable.ty);
+        quote! {
+            #( #cfg )*
+            <#ty as ::juniper_compose::ComposableObject<#scalar>>::TYPE_NAME
+        }
+    });
+    quote! {
+        ::juniper_compose::inventory::submit! {
+            ::juniper_compose::registry::Composite {
+                name: #name_lit,
+                type_path: ::std::concat!(::std::module_path!(), "::", ::std::stringify!(#ident)),
+                members: &[#( #members ),*],
+            }
+        }
+    }
+}
+
+/// Implements `build_schema()` on a composite object with the `test_helpers` feature, which
+/// builds a `RootNode` of it with an empty mutation and subscription to execute queries against
+/// in tests. It takes the type info of the composite if it has some, unless given with
+/// `info = expr`.
+fn expand_impl_build_schema(input: &CompositeInput) -> TokenStream {
+    if !cfg!(feature = "test_helpers") {
+        return TokenStream::new();
+    }
+    let vis = &input.vis;
+    let self_ty = input.self_ty();
+    let context = input.context();
+    let info = input.info();
+    let scalar = input.scalar();
+    let (impl_generics, bounds) = input.inherent_impl_generics();
+    // A generic scalar value type is a parameter of the function instead, with the predicates
+    // that may mention it.
+    let (fn_generics, fn_bounds) = if input.has_generic_scalar() {
+        let bounds = input.impl_generics().1;
+        (quote! { <#scalar> }, quote! { where #bounds })
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+    let (info_param, info_arg) = if input.info_value.is_some() {
+        (TokenStream::new(), quote! { Self::info() })
+    } else if input.has_info() {
+        (quote! { info: #info }, quote! { info })
+    } else {
+        (TokenStream::new(), quote! { () })
+    };
+    quote! {
+        impl #impl_generics #self_ty
+        where
+            #bounds
+        {
+            /// Builds a schema of this query type constructed with `Default`, with an empty
+            /// mutation and subscription, e.g. to execute queries against it in tests.
+            #[must_use]
+            #vis fn build_schema #fn_generics(#info_param) -> ::juniper::RootNode<
+                'static,
+                Self,
+                ::juniper::EmptyMutation<#context>,
+                ::juniper::EmptySubscription<#context>,
+                #scalar,
+            >
+            #fn_bounds
+            {
+                ::juniper::RootNode::new_with_info(
+                    <Self as ::std::default::Default>::default(),
+                    ::juniper::EmptyMutation::new(),
+                    ::juniper::EmptySubscription::new(),
+                    #info_arg,
+                    (),
+                    (),
+                )
+            }
+        }
+    }
+}
+
+pub fn expand_assert_disjoint(input: &CompositeInput, kind: ComposableKind) -> TokenStream {
+    let scalar = input.const_scalar();
+    let composable_trait = kind.trait_path();
+    let names = kind.names_const();
+    let composables = input.composables.iter().collect::<Vec<_>>();
+    let descriptors = composables
+        .iter()
+        .map(|composable| {
+            let ty = input.to_static(&composable.ty);
+            composable.descriptor(&quote! { <#ty as #composable_trait<#scalar>>::#names })
+        })
+        .collect::<Vec<_>>();
+    expand_members_disjoint(&composables, &descriptors, kind)
+}
+
+/// Asserts at compile time that `composables` rename, exclude and select fields they have, and
+/// that they expose disjoint names, given the `juniper_compose::member::Member` descriptor of
+/// each of them.
+pub fn expand_members_disjoint(
+    composables: &[&CompositeMember],
+    descriptors: &[TokenStream],
+    kind: ComposableKind,
+) -> TokenStream {
+    let rename_assertions = composables
+        .iter()
+        .zip(descriptors)
+        .filter(|(composable, _)| composable.has_renames())
+        .map(|(composable, descriptor)| {
+            let cfg = &composable.cfg;
+            quote_spanned! {composable.ty.span()=>
+                #( #cfg )*
+                const _: () = ::juniper_compose::check::assert_renamed_fields_exist(&#descriptor);
+            }
+        });
+    let exclusion_assertions = composables
+        .iter()
+        .zip(descriptors)
+        .filter(|(composable, _)| composable.has_excluded())
+        .map(|(composable, descriptor)| {
+            let cfg = &composable.cfg;
+            quote_spanned! {composable.ty.span()=>
+                #( #cfg )*
+                const _: () = ::juniper_compose::check::assert_excluded_fields_exist(&#descriptor);
+            }
+        });
+    let selection_assertions = composables
+        .iter()
+        .zip(descriptors)
+        .filter(|(composable, _)| composable.has_only())
+        .map(|(composable, descriptor)| {
+            let cfg = &composable.cfg;
+            quote_spanned! {composable.ty.span()=>
+                #( #cfg )*
+                const _: () = ::juniper_compose::check::assert_selected_fields_exist(&#descriptor);
+            }
+        });
+    // The members are checked against each other in a single pass over their names.
+    let cfgs = composables.iter().map(|composable| &composable.cfg);
+    let check = match kind {
+        ComposableKind::Union => "assert_variants_disjoint",
+        _ => "assert_disjoint",
+    };
+    let check = Ident::new(check, Span::call_site());
+    let disjoint_assertion = (composables.len() > 1).then(|| {
+        quote! {
+            const _: () = ::juniper_compose::check::#check(&[#( #( #cfgs )* #descriptors ),*]);
+        }
+    });
+    quote! {
+        #( #rename_assertions )*
+        #( #exclusion_assertions )*
+        #( #selection_assertions )*
+        #disjoint_assertion
+    }
+}
+
+/// Asserts that every member is a composable of `kind` with the context and type info of the
+/// composite (or the context it is resolved with), in a single bound reported at the member. A
+/// member failing it gets one error naming it, ahead of the errors of the generated code relying
+/// on it.
+pub fn expand_assert_composable(input: &CompositeInput, kind: ComposableKind) -> TokenStream {
+    let context = input.to_static(&input.context());
+    let info = input.to_static(&input.info());
+    let const_scalar = input.const_scalar();
+    let assert_fn = kind.assert_fn();
+    let assertions = input.listed_members().map(|composable| {
+        let ty = input.to_static(&composable.ty);
+        let cfg = &composable.cfg;
+        let context = composable.context_as.as_ref().map_or_else(
+            || context.clone(),
+            |member_context| input.to_static(member_context),
+        );
+        quote_spanned! {composable.ty.span()=>
+            #( #cfg )*
+            const _: () =
+                ::juniper_compose::check::#assert_fn::<#ty, #context, #info, #const_scalar>();
+        }
+    });
+    quote! { #( #assertions )* }
+}
+
+/// Asserts at compile time that the context of the composite is `Sync`, and that the contexts
+/// members are resolved with can be converted from it.
+pub fn expand_assert_context(input: &CompositeInput) -> TokenStream {
+    let context = input.to_static(&input.context());
+    // Reported at the context itself, where the bounds of the generated impls would only be
+    // reported at the invocation.
+    let assert_sync = input.explicit_context().map(|explicit_context| {
+        quote_spanned! {explicit_context.span()=>
+            const _: () = ::juniper_compose::check::assert_context_sync::<#context>();
+        }
+    });
+    let assertions = input.composables.iter().map(|composable| {
+        let cfg = &composable.cfg;
+        composable.context_as.as_ref().map(|member_context| {
+            let member_context = input.to_static(member_context);
+            quote_spanned! {member_context.span()=>
+                #( #cfg )*
+                const _: () = ::juniper_compose::check::assert_context_from::<#member_context, #context>();
+            }
+        })
+    });
+    quote! {
+        #assert_sync
+        #( #assertions )*
+    }
+}
+
+/// Implements the composable trait of `kind` for the composite, so that it can be composed into
+/// another composite. Its fields (or variant types) are the ones it exposes, computed in constant
+/// evaluation.
+pub fn expand_impl_composable(input: &CompositeInput, kind: ComposableKind) -> TokenStream {
+    let self_ty = input.self_ty();
+    let scalar = input.scalar();
+    let (impl_generics, bounds) = input.impl_generics();
+    let composable_trait = kind.trait_path();
+    let names = kind.names_const();
+    let fields = expand_exposed_names(input, kind, &names.to_token_stream());
+    let name_lit = input.name_lit();
+    let object_items = match kind {
+        ComposableKind::Object => {
+            let async_fields = input.sync.is_none().then(|| {
+                let async_fields = expand_exposed_names(input, kind, &quote! { ASYNC_FIELDS });
+                quote! { const ASYNC_FIELDS: &'static [&'static str] = #async_fields; }
+            });
+            let converted_fields = expand_converted_fields(input);
+            let dynamic_fields = expand_dynamic_fields(input);
+            let field_types = expand_exposed_field_types(input, kind);
+            let fields_fn = expand_fields_fn(input, kind);
+            let fields_auth_fn = expand_fields_auth_fn(input);
+            Some(quote! {
+                const TYPE_NAME: &'static str = #name_lit;
+                #async_fields
+                const CONVERTED_FIELDS: &'static [&'static str] = #converted_fields;
+                const DYNAMIC_FIELDS: bool = #dynamic_fields;
+                const FIELD_TYPES: &'static [::juniper_compose::FieldType] = #field_types;
+                #fields_fn
+                #fields_auth_fn
+            })
+        }
+        ComposableKind::Interface => {
+            let field_types = expand_exposed_field_types(input, kind);
+            Some(quote! {
+                const FIELD_TYPES: &'static [::juniper_compose::FieldType] = #field_types;
+            })
+        }
+        _ => None,
+    };
+    // Unions are resolved like objects, so a generic scalar has to be resolvable asynchronously.
+    let scalar_bounds = matches!(kind, ComposableKind::Union).then(|| {
+        quote! { #scalar: ::std::marker::Send + ::std::marker::Sync, }
+    });
+    quote! {
+        impl #impl_generics #composable_trait<#scalar> for #self_ty
+        where
+            #bounds
+            #scalar_bounds
+        {
+            const #names: &'static [&'static str] = #fields;
+            #object_items
+        }
+    }
+}
+
+/// Builds the constant expression telling whether any member of the composite object only knows
+/// its fields at run time, which makes the composite one too.
+fn expand_dynamic_fields(input: &CompositeInput) -> TokenStream {
+    let const_scalar = input.const_scalar();
+    let cfgs = expand_member_cfgs(input);
+    let composables = input
+        .composables
+        .iter()
+        .map(|composable| input.to_static(&composable.ty));
+    quote! {
+        {
+            const FLAGS: &[bool] = &[#(
+                #cfgs <#composables as ::juniper_compose::ComposableObject<#const_scalar>>::DYNAMIC_FIELDS
+            ),*];
+            ::juniper_compose::member::any(FLAGS)
+        }
+    }
+}
+
+/// Overrides `fields()` with the names the composite exposes the `fields()` of its members under,
+/// which differ from its `FIELDS` if some members only know theirs at run time.
+fn expand_fields_fn(input: &CompositeInput, kind: ComposableKind) -> TokenStream {
+    let static_self_ty = input.to_static(&input.self_ty());
+    let descriptors = expand_member_descriptors(input, kind, &input.scalar());
+    let cfgs = expand_member_cfgs(input);
+    quote! {
+        fn fields() -> &'static [&'static str] {
+            ::juniper_compose::dynamic_fields::<#static_self_ty>(|| {
+                ::juniper_compose::member::exposed_field_names(&[#( #cfgs #descriptors ),*])
+            })
+        }
+    }
+}
+
+/// Overrides `fields_auth()` with the names the composite exposes the fields of its members
+/// under, along with the roles the members require for them.
+fn expand_fields_auth_fn(input: &CompositeInput) -> TokenStream {
+    let static_self_ty = input.to_static(&input.self_ty());
+    let scalar = input.scalar();
+    let descriptors = expand_member_descriptors(input, ComposableKind::Object, &scalar);
+    let cfgs = expand_member_cfgs(input);
+    let members_auth = input.composables.iter().map(|composable| {
+        let ty = &composable.ty;
+        quote! { <#ty as ::juniper_compose::ComposableObject<#scalar>>::fields_auth() }
+    });
+    quote! {
+        fn fields_auth() -> &'static [(&'static str, ::std::option::Option<&'static str>)]
+        where
+            Self: 'static,
+        {
+            ::juniper_compose::fields_auth::<#static_self_ty>(|| {
+                ::juniper_compose::member::exposed_fields_auth(
+                    &[#( #cfgs #descriptors ),*],
+                    &[#( #cfgs #members_auth ),*],
+                )
+            })
+        }
+    }
+}
+
+/// Builds the constant expression listing the names the composite exposes the `names` fields of
+/// its members under, where `names` is an associated constant of the composable trait.
+fn expand_exposed_names(
+    input: &CompositeInput,
+    kind: ComposableKind,
+    names: &TokenStream,
+) -> TokenStream {
+    let const_scalar = input.const_scalar();
+    let composable_trait = kind.trait_path();
+    let descriptors = input.composables.iter().map(|composable| {
+        let ty = input.to_static(&composable.ty);
+        composable.descriptor(&quote! { <#ty as #composable_trait<#const_scalar>>::#names })
+    });
+    expand_names_of(input, descriptors)
+}
+
+/// Builds the constant expression listing the names the composite exposes the converted fields
+/// of its members under, leaving out the ones it renames.
+fn expand_converted_fields(input: &CompositeInput) -> TokenStream {
+    let const_scalar = input.const_scalar();
+    let descriptors = input.composables.iter().map(|composable| {
+        let ty = input.to_static(&composable.ty);
+        composable.descriptor_excluding(
+            &quote! {
+                <#ty as ::juniper_compose::ComposableObject<#const_scalar>>::CONVERTED_FIELDS
+            },
+            &composable.renamed_fields(),
+        )
+    });
+    expand_names_of(input, descriptors)
+}
+
+/// Builds the constant expression listing the names the members described by `descriptors`, one
+/// for each member of the composite, expose.
+fn expand_names_of(
+    input: &CompositeInput,
+    descriptors: impl Iterator<Item = TokenStream>,
+) -> TokenStream {
+    let cfgs = expand_member_cfgs(input);
+    quote! {
+        {
+            const MEMBERS: &[::juniper_compose::member::Member] = &[#( #cfgs #descriptors ),*];
+            const BYTES: [u8; ::juniper_compose::member::exposed_names_len(MEMBERS)] =
+                ::juniper_compose::member::exposed_names_bytes(MEMBERS);
+            const NAMES: [&str; ::juniper_compose::member::field_count(MEMBERS)] =
+                ::juniper_compose::member::exposed_names(MEMBERS, &BYTES);
+            &NAMES
+        }
+    }
+}
+
+/// Builds the constant expression listing the types of the fields the composite exposes, in the
+/// order of its `FIELDS`, from the `FIELD_TYPES` of its members. It is empty if a member doesn't
+/// know the types of its fields.
+fn expand_exposed_field_types(input: &CompositeInput, kind: ComposableKind) -> TokenStream {
+    let const_scalar = input.const_scalar();
+    let composable_trait = kind.trait_path();
+    let names = kind.names_const();
+    let composables = input
+        .composables
+        .iter()
+        .map(|composable| input.to_static(&composable.ty))
+        .collect::<Vec<_>>();
+    let descriptors = input
+        .composables
+        .iter()
+        .zip(&composables)
+        .map(|(composable, ty)| {
+            composable.descriptor(&quote! { <#ty as #composable_trait<#const_scalar>>::#names })
+        });
+    let cfgs = expand_member_cfgs(input);
+    quote! {
+        {
+            const MEMBERS: &[::juniper_compose::member::Member] = &[#( #cfgs #descriptors ),*];
+            const TYPES: &[&[::juniper_compose::FieldType]] = &[#(
+                #cfgs <#composables as #composable_trait<#const_scalar>>::FIELD_TYPES
+            ),*];
+            const FIELD_TYPES: [
+                ::juniper_compose::FieldType;
+                ::juniper_compose::member::field_types_len(MEMBERS, TYPES)
+            ] = ::juniper_compose::member::exposed_field_types(MEMBERS, TYPES);
+            &FIELD_TYPES
+        }
+    }
+}
+
+/// Asserts at compile time that no field of a `sync` composite object is resolved
+/// asynchronously.
+pub fn expand_assert_sync(input: &CompositeInput) -> TokenStream {
+    if input.sync.is_none() {
+        return TokenStream::new();
+    }
+    let name_lit = type_name_lit(&input.ident);
+    let const_scalar = input.const_scalar();
+    let assertions = input.composables.iter().map(|composable| {
+        let ty = input.to_static(&composable.ty);
+        let member = composable.descriptor(&quote! {
+            <#ty as ::juniper_compose::ComposableObject<#const_scalar>>::ASYNC_FIELDS
+        });
+        let cfg = &composable.cfg;
+        quote_spanned! {composable.ty.span()=>
+            #( #cfg )*
+            const _: () = ::juniper_compose::check::assert_sync(#name_lit, &#member);
+        }
+    });
+    quote! { #( #assertions )* }
+}
+
+/// Asserts at compile time that no member of a composite object declared `rename_all = "none"`
+/// exposes a field whose name its own `rename_all` policy converted.
+fn expand_assert_unconverted(input: &CompositeInput) -> TokenStream {
+    if !input.keeps_names() {
+        return TokenStream::new();
+    }
+    let name_lit = type_name_lit(&input.ident);
+    let const_scalar = input.const_scalar();
+    let assertions = input.composables.iter().map(|composable| {
+        let ty = input.to_static(&composable.ty);
+        let member = composable.descriptor(&quote! {
+            <#ty as ::juniper_compose::ComposableObject<#const_scalar>>::CONVERTED_FIELDS
+        });
+        let cfg = &composable.cfg;
+        quote_spanned! {composable.ty.span()=>
+            #( #cfg )*
+            const _: () = ::juniper_compose::check::assert_unconverted(#name_lit, &#member);
+        }
+    });
+    quote! { #( #assertions )* }
+}
+
+/// Builds the runtime `juniper_compose::member::Member` of every composable, with its fields for
+/// `scalar`, in order.
+pub fn expand_member_descriptors(
+    input: &CompositeInput,
+    kind: ComposableKind,
+    scalar: &Type,
+) -> Vec<TokenStream> {
+    let composable_trait = kind.trait_path();
+    input
+        .composables
+        .iter()
+        .map(|composable| {
+            let ty = &composable.ty;
+            composable.descriptor(&quote! { <#ty as #composable_trait<#scalar>>::fields() })
+        })
+        .collect()
+}
+
+/// Builds the expression evaluating to the `juniper_compose::member::DispatchTable` of the
+/// composite, which locates the member that resolves each field, with the fields of its members
+/// for `scalar`. It is built the first time it is used.
+pub fn expand_dispatch_table(
+    input: &CompositeInput,
+    kind: ComposableKind,
+    scalar: &Type,
+) -> TokenStream {
+    let descriptors = expand_member_descriptors(input, kind, scalar);
+    let indices = expand_member_indices(input);
+    let cfgs = expand_member_cfgs(input);
+    quote! {
+        {
+            static TABLE: ::std::sync::OnceLock<::juniper_compose::member::DispatchTable> =
+                ::std::sync::OnceLock::new();
+            TABLE.get_or_init(|| {
+                ::juniper_compose::member::DispatchTable::new(&[
+                    #( #cfgs (#indices, #descriptors) ),*
+                ])
+            })
+        }
+    }
+}
+
+/// Implements `field_origin` for the composite, which tells the composable resolving a field,
+/// and `member_type_names`, which lists the composables in the order they are listed.
+pub fn expand_impl_field_origin(input: &CompositeInput, kind: ComposableKind) -> TokenStream {
+    let vis = &input.vis;
+    let self_ty = input.self_ty();
+    // Field names don't depend on the scalar value type, so a generic one is instantiated.
+    let dispatch_table = expand_dispatch_table(input, kind, &input.const_scalar());
+    let member_type_names = input.listed_members().map(|composable| {
+        let cfg = &composable.cfg;
+        let type_name = composable.type_name_lit();
+        quote! { #( #cfg )* #type_name }
+    });
+    let (impl_generics, bounds) = input.inherent_impl_generics();
+    quote! {
+        impl #impl_generics #self_ty
+        where
+            #bounds
+        {
+            /// Returns the name of the composed type that resolves the field this type exposes
+            /// under `name`, or `None` if it exposes no such field.
+            #vis fn field_origin(name: &str) -> ::std::option::Option<&'static str> {
+                #dispatch_table.origin(name)
+            }
+
+            /// Returns the names of the composed types, in the order they are listed, as
+            /// [`field_origin`](Self::field_origin) names them. Types left out by a `cfg`
+            /// attribute aren't listed.
+            #vis fn member_type_names() -> &'static [&'static str] {
+                &[#( #member_type_names ),*]
+            }
+        }
+    }
+}
+
+/// Defines `FIELD_COUNT` for the composite, the number of fields it exposes, so that it can be
+/// asserted on in constant evaluation.
+pub fn expand_impl_field_count(input: &CompositeInput, kind: ComposableKind) -> TokenStream {
+    let vis = &input.vis;
+    let self_ty = input.self_ty();
+    let scalar = input.const_scalar();
+    let composable_trait = kind.trait_path();
+    let (impl_generics, bounds) = input.inherent_impl_generics();
+    quote! {
+        impl #impl_generics #self_ty
+        where
+            #bounds
+        {
+            /// The number of fields this type exposes, as listed by its `FIELDS`.
+            #vis const FIELD_COUNT: usize = <Self as #composable_trait<#scalar>>::FIELDS.len();
+        }
+    }
+}
+
+/// Returns the index literal of every composable, in order, to match the members found in the
+/// table built by [`expand_dispatch_table`] against.
+pub fn expand_member_indices(input: &CompositeInput) -> Vec<Literal> {
+    (0..input.composables.len())
+        .map(Literal::usize_unsuffixed)
+        .collect()
+}
+
+/// Returns the `cfg` attributes of every composable, in order, for the items and expressions
+/// referring to it.
+pub fn expand_member_cfgs(input: &CompositeInput) -> Vec<TokenStream> {
+    input
+        .composables
+        .iter()
+        .map(|composable| {
+            let cfg = &composable.cfg;
+            quote! { #( #cfg )* }
+        })
+        .collect()
+}
+
+/// Builds the expression constructing every composable, in order.
+pub fn expand_member_constructors(input: &CompositeInput) -> Vec<TokenStream> {
+    let scalar = input.scalar();
+    let context = input.context();
+    input
+        .composables
+        .iter()
+        .map(|composable| composable.constructor(&scalar, &context))
+        .collect()
+}
+
+fn type_name_lit(ty: &impl ToTokens) -> LitStr {
+    let name = ty.to_token_stream().to_string().replace(' ', "");
+    LitStr::new(&name, Span::call_site())
+}
+
+/// Builds the meta type of the composite from its merged `fields`.
+fn expand_build_type(
+    input: &CompositeInput,
+    kind: ComposableKind,
+    implementers: &[Path],
+) -> TokenStream {
+    let description = input.description();
+    let scalar = input.scalar();
+    match kind {
+        ComposableKind::Object if input.interfaces.is_some() => {
+            let interfaces = input.interfaces();
+            quote! {
+                let interfaces = [#( registry.get_type::<#interfaces>(info) ),*];
+                ::juniper_compose::dispatch::object_meta::<Self, #scalar>(info, registry, fields)
+                    .interfaces(&interfaces)
+                    #description
+                    .into_meta()
+            }
+        }
+        ComposableKind::Object | ComposableKind::Subscription => quote! {
+            ::juniper_compose::dispatch::object_meta::<Self, #scalar>(info, registry, fields)
+                #description
+                .into_meta()
+        },
+        ComposableKind::Interface => quote! {
+            #( let _ = registry.get_type::<#implementers>(info); )*
+            ::juniper_compose::dispatch::interface_meta::<Self, #scalar>(info, registry, fields)
+                #description
+                .into_meta()
+        },
+        ComposableKind::InputObject | ComposableKind::Union => {
+            unreachable!("only objects and interfaces are built from fields")
+        }
+    }
+}
+
+/// Builds the `GraphQLType` impl of the composite, merging the fields of its members.
+/// `implementers` are the object types implementing it, if it is an interface.
+pub fn expand_impl_graphql_type(
+    input: &CompositeInput,
+    kind: ComposableKind,
+    implementers: &[Path],
+) -> TokenStream {
+    let self_ty = input.self_ty();
+    let name_lit = input.name_lit();
+    let scalar = input.scalar();
+    let (impl_generics, bounds) = input.impl_generics();
+    let incorrect_implementation = LitStr::new(
+        &format!(
+            "Incorrect implementation of {} on type {{}}: unknown field {{}}",
+            kind.trait_name(),
+        ),
+        Span::call_site(),
+    );
+    let composables = input
+        .composables
+        .iter()
+        .map(|composable| &composable.ty)
+        .collect::<Vec<_>>();
+    let descriptors = expand_member_descriptors(input, kind, &input.scalar());
+    let build_type = expand_build_type(input, kind, implementers);
+    let fields = expand_cached_fields(input);
+    let cfgs = expand_member_cfgs(input);
+    let composable_metas = (0..input.composables.len())
+        .map(|index| format_ident!("composable_meta_{}", index))
+        .collect::<Vec<_>>();
+    // The fields of the fallback that other members expose are left out.
+    let shadowing = input.composables.iter().map(|composable| {
+        composable.fallback.is_some().then(|| {
+            quote! {
+                if member.is_shadowed(&exposed_name, &[#( #cfgs #descriptors ),*]) {
+                    continue;
+                }
+            }
+        })
+    });
+    // Fields are registered in the order members are listed, and within each member in the
+    // order of its `fields()`, so the schema is the same from build to build. The set of seen
+    // names is only used to detect conflicts. `TypeRegistrations` checks the types the members
+    // register in `merge`, so only when the fields are merged rather than read from the cache.
+    let sort = expand_sort_fields(input);
+    let register_types = expand_register_types(input);
+    quote! {
+        impl #impl_generics ::juniper::GraphQLType<#scalar> for #self_ty
+        where
+            #bounds
+        {
+            fn name(info: &Self::TypeInfo) -> ::std::option::Option<&str> {
+                ::std::option::Option::Some(#name_lit)
+            }
+
+            fn meta<'__r>(
+                info: &Self::TypeInfo,
+                registry: &mut ::juniper::executor::Registry<'__r, #scalar>
+            ) -> ::juniper::meta::MetaType<'__r, #scalar>
+            where
+                #scalar: '__r
+            {
+                #(
+                    #cfgs
+                    let #composable_metas = <#composables as ::juniper::GraphQLType<#scalar>>::meta(info, registry);
+                )*
+
+                let merge = || {
+                    #register_types
+
+                    let mut fields = ::std::vec![];
+                    let mut seen_field_names = ::std::collections::HashSet::<::std::string::String>::new();
+
+                    #(
+                        #cfgs
+                        {
+                            let member = #descriptors;
+
+                            for &field_name in member.fields.iter().filter(|field| member.exposes(field)) {
+                                let composable_field = #composable_metas
+                                    .field_by_name(field_name)
+                                    .unwrap_or_else(|| {
+                                        ::std::panic!(
+                                            #incorrect_implementation,
+                                            <#composables as ::juniper::GraphQLType<#scalar>>::name(info).unwrap_or("<anonymous>"), field_name
+                                        )
+                                    });
+
+                                for index in 0..member.name_count(field_name) {
+                                    let exposed_name = member.name(field_name, index);
+                                    #shadowing
+                                    let exposed_name = exposed_name.to_string();
+                                    if !seen_field_names.insert(exposed_name.clone()) {
+                                        ::std::panic!("Conflicting field in composed objects: {}", exposed_name);
+                                    }
+
+                                    // Aliases are deprecated in favor of the exposed name.
+                                    let deprecation_status = member.deprecation_status(
+                                        field_name,
+                                        index,
+                                        &composable_field.deprecation_status,
+                                    );
+                                    fields.push(::juniper_compose::dispatch::exposed_field(
+                                        composable_field,
+                                        &exposed_name,
+                                        deprecation_status,
+                                    ));
+                                }
+                            }
+                        }
+                    )*
+
+                    #sort
+                    fields
+                };
+                let fields = #fields;
+
+                #build_type
+            }
+        }
+    }
+}
+
+/// Registers the types every member refers to, which panics if two of them register different
+/// types of the same name. Juniper would ignore the second one.
+pub fn expand_register_types(input: &CompositeInput) -> TokenStream {
+    let name_lit = input.name_lit();
+    let scalar = input.scalar();
+    let composables = input.composables.iter().map(|composable| &composable.ty);
+    let cfgs = expand_member_cfgs(input);
+    quote! {
+        let mut types = ::juniper_compose::dispatch::TypeRegistrations::new(#name_lit);
+        #(
+            #cfgs
+            types.register::<#composables, #scalar>(info);
+        )*
+    }
+}
+
+/// Calls `merge`, which merges the fields of the members, through the cache of merged fields.
+/// Fields depend on the type info if there is one, so they can only be cached without. They
+/// don't depend on the lifetime parameters, which are erased from the key of the cache.
+fn expand_cached_fields(input: &CompositeInput) -> TokenStream {
+    let static_self_ty = input.to_static(&input.self_ty());
+    let scalar = input.scalar();
+    if input.has_info() {
+        quote! { merge() }
+    } else {
+        quote! { ::juniper_compose::cache::merged_fields::<#static_self_ty, #scalar>(merge) }
+    }
+}
+
+/// Sorts the merged `fields` of a composite declared `sorted` by name, which only changes the
+/// order of its meta type, not how fields are dispatched.
+fn expand_sort_fields(input: &CompositeInput) -> Option<TokenStream> {
+    input.sorted.is_some().then(|| {
+        quote! { fields.sort_by(|left, right| left.name.cmp(&right.name)); }
+    })
+}
+
+fn expand_impl_graphql_value(input: &CompositeInput) -> TokenStream {
+    let self_ty = input.self_ty();
+    let name_lit = input.name_lit();
+    let context = input.context();
+    let info = input.info();
+    let scalar = input.scalar();
+    let composables = input.composables.iter().map(|composable| &composable.ty);
+    let dispatch_table = expand_dispatch_table(input, ComposableKind::Object, &input.scalar());
+    let indices = expand_member_indices(input);
+    let cfgs = expand_member_cfgs(input);
+    let constructors = expand_member_constructors(input);
+    let executors = input
+        .composables
+        .iter()
+        .map(|composable| composable.executor(&context));
+    let map_err = input
+        .map_err
+        .as_ref()
+        .map(|(_, map_err)| quote! { .map_err(#map_err) });
+    let calls = composables
+        .zip(constructors)
+        .zip(executors)
+        .map(|((ty, constructor), executor)| {
+            quote! {
+                <#ty as ::juniper::GraphQLValue<#scalar>>::resolve_field(
+                    &#constructor,
+                    info,
+                    field_name,
+                    arguments,
+                    #executor
+                )
+                #map_err
+            }
+        })
+        .zip(&input.composables)
+        .map(|(call, composable)| {
+            let disabled = quote! {
+                ::std::result::Result::Err(
+                    ::juniper_compose::dispatch::unknown_field(#name_lit, exposed_name),
+                )
+            };
+            composable.gate(&scalar, &context, call, &disabled)
+        })
+        .collect::<Vec<_>>();
+    let fallback = expand_fallback_arm(input, &calls);
+    let exposed_name = input.expand_exposed_name();
+    let (impl_generics, bounds) = input.impl_generics();
+    quote! {
+        impl #impl_generics ::juniper::GraphQLValue<#scalar> for #self_ty
+        where
+            #bounds
+        {
+            type Context = #context;
+            type TypeInfo = #info;
+
+            fn type_name<'__i>(&self, info: &'__i Self::TypeInfo) -> Option<&'__i str> {
+                <Self as ::juniper::GraphQLType<#scalar>>::name(info)
+            }
+
+            fn resolve_field(
+                &self,
+                info: &Self::TypeInfo,
+                field_name: &str,
+                arguments: &::juniper::Arguments<'_, #scalar>,
+                executor: &::juniper::executor::Executor<'_, '_, Self::Context, #scalar>
+            ) -> ::juniper::executor::ExecutionResult<#scalar> {
+                #exposed_name
+                match #dispatch_table.find(field_name) {
+                    #(
+                        #cfgs
+                        ::std::option::Option::Some((#indices, field_name)) => #calls,
+                    )*
+                    #fallback
+                    _ => ::std::result::Result::Err(
+                        ::juniper_compose::dispatch::unknown_field(#name_lit, field_name),
+                    ),
+                }
+            }
+
+            fn concrete_type_name(
+                &self,
+                context: &Self::Context,
+                info: &Self::TypeInfo
+            ) -> String {
+                String::from(#name_lit)
+            }
+        }
+    }
+}
+
+/// Builds the match arm resolving the fields no member exposes with the fallback member, with
+/// `calls`, the expression resolving a field with each member. The arm of unknown fields
+/// following it is unreachable unless the fallback is left out by a `cfg` attribute.
+fn expand_fallback_arm(input: &CompositeInput, calls: &[TokenStream]) -> TokenStream {
+    let Some(index) = input.fallback_index() else {
+        return TokenStream::new();
+    };
+    let cfg = &input.composables[index].cfg;
+    let call = &calls[index];
+    quote! {
+        #( #cfg )*
+        _ => #call,
+        #[allow(unreachable_patterns)]
+    }
+}
+
+fn expand_impl_graphql_value_async(input: &CompositeInput) -> TokenStream {
+    let self_ty = input.self_ty();
+    let name_lit = input.name_lit();
+    let scalar = input.scalar();
+    let context = input.context();
+    // Members resolved with a converted context are resolved with an executor of it.
+    let resolvers = input.composables.iter().map(|composable| {
+        let ty = &composable.ty;
+        if composable.context_as.is_some() {
+            quote! { resolve_field_async_as::<#ty, #context, #scalar> }
+        } else {
+            quote! { resolve_field_async::<#ty, #scalar> }
+        }
+    });
+    let dispatch_table = expand_dispatch_table(input, ComposableKind::Object, &input.scalar());
+    let indices = expand_member_indices(input);
+    let cfgs = expand_member_cfgs(input);
+    let constructors = expand_member_constructors(input);
+    let calls = resolvers
+        .zip(constructors)
+        .map(|(resolver, constructor)| {
+            let resolve = quote! {
+                ::juniper_compose::dispatch::#resolver(
+                    #constructor,
+                    info,
+                    field_name,
+                    arguments,
+                    executor
+                )
+            };
+            match &input.map_err {
+                Some((_, map_err)) => {
+                    quote! { ::juniper_compose::dispatch::map_err(#resolve, #map_err) }
+                }
+                None => resolve,
+            }
+        })
+        .zip(&input.composables)
+        .map(|(call, composable)| {
+            let disabled = quote! {
+                ::std::boxed::Box::pin(::std::future::ready(::std::result::Result::Err(
+                    ::juniper_compose::dispatch::unknown_field(#name_lit, exposed_name),
+                )))
+            };
+            composable.gate(&scalar, &context, call, &disabled)
+        })
+        .collect::<Vec<_>>();
+    let fallback = expand_fallback_arm(input, &calls);
+    let exposed_name = input.expand_exposed_name();
+    let (impl_generics, bounds) = input.impl_generics();
+    quote! {
+        impl #impl_generics ::juniper::GraphQLValueAsync<#scalar> for #self_ty
+        where
+            #bounds
+            Self::TypeInfo: Sync,
+            Self::Context: Sync,
+        {
+            fn resolve_field_async<'__a>(
+                &'__a self,
+                info: &'__a Self::TypeInfo,
+                field_name: &'__a str,
+                arguments: &'__a ::juniper::Arguments<'_, #scalar>,
+                executor: &'__a ::juniper::executor::Executor<'_, '_, Self::Context, #scalar>
+            ) -> ::juniper::BoxFuture<'__a, ::juniper::executor::ExecutionResult<#scalar>> {
+                #exposed_name
+                match #dispatch_table.find(field_name) {
+                    #(
+                        #cfgs
+                        ::std::option::Option::Some((#indices, field_name)) => #calls,
+                    )*
+                    #fallback
+                    _ => ::std::boxed::Box::pin(::std::future::ready(::std::result::Result::Err(
+                        ::juniper_compose::dispatch::unknown_field(#name_lit, field_name),
+                    ))),
+                }
+            }
+        }
+    }
+}
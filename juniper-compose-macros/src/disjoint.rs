@@ -0,0 +1,41 @@
+use crate::{
+    composable::ComposableKind,
+    composite::{CompositeMember, expand_members_disjoint},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Result, parse::Parse, parse_quote, punctuated::Punctuated, token::Comma};
+
+/// Input of the `assert_fields_disjoint!` macro: composable objects listed the way
+/// `composite_object!` lists its members.
+pub struct DisjointInput {
+    composables: Punctuated<CompositeMember, Comma>,
+}
+
+impl Parse for DisjointInput {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let mut composables = input.parse_terminated(CompositeMember::parse, Comma)?;
+        for composable in &mut composables {
+            composable.instantiate_scalar(&parse_quote! { ::juniper::DefaultScalarValue })?;
+        }
+        Ok(Self { composables })
+    }
+}
+
+/// Asserts at compile time that the listed objects could be composed in a composite object with
+/// the default scalar value type, without composing them.
+pub fn expand_assert_fields_disjoint(input: &DisjointInput) -> TokenStream {
+    let kind = ComposableKind::Object;
+    let composable_trait = kind.trait_path();
+    let composables = input.composables.iter().collect::<Vec<_>>();
+    let descriptors = composables
+        .iter()
+        .map(|composable| {
+            let ty = &composable.ty;
+            composable.descriptor(&quote! {
+                <#ty as #composable_trait<::juniper::DefaultScalarValue>>::FIELDS
+            })
+        })
+        .collect::<Vec<_>>();
+    expand_members_disjoint(&composables, &descriptors, kind)
+}
@@ -0,0 +1,311 @@
+use crate::{
+    composable::ComposableKind,
+    composite::{
+        CompositeInput, expand_assert_composable, expand_assert_context, expand_assert_disjoint,
+        expand_assert_has_fields, expand_dispatch_table, expand_impl_composable,
+        expand_impl_field_count, expand_member_descriptors, expand_member_indices,
+        expand_register_types,
+    },
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, Index, Result, spanned::Spanned};
+
+pub fn expand_composite_input_object(input: &CompositeInput) -> TokenStream {
+    if let Err(error) = reject_unsupported(input) {
+        return error.into_compile_error();
+    }
+    let assert_composable = expand_assert_composable(input, ComposableKind::InputObject);
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+    let name = &input.ident;
+    let composables = input.composables.iter().map(|composable| &composable.ty);
+    let impl_graphql_type = expand_impl_graphql_type(input);
+    let impl_graphql_value = expand_impl_graphql_value(input);
+    let impl_from_input_value = expand_impl_from_input_value(input);
+    let impl_to_input_value = expand_impl_to_input_value(input);
+    let impl_marker_traits = expand_impl_marker_traits(input);
+    let impl_composable = expand_impl_composable(input, ComposableKind::InputObject);
+    let impl_field_count = expand_impl_field_count(input, ComposableKind::InputObject);
+    let assert_has_fields = expand_assert_has_fields(input, ComposableKind::InputObject);
+    let assert_disjoint = expand_assert_disjoint(input, ComposableKind::InputObject);
+    let assert_context = expand_assert_context(input);
+    quote! {
+        #assert_composable
+        #( #attrs )*
+        #vis struct #name(#( pub #composables ),*);
+        #impl_graphql_type
+        #impl_graphql_value
+        #impl_from_input_value
+        #impl_to_input_value
+        #impl_marker_traits
+        #impl_composable
+        #impl_field_count
+        #assert_has_fields
+        #assert_disjoint
+        #assert_context
+    }
+}
+
+/// Every field of an input object is read from the input value, and the struct holds a value of
+/// every member, so members can only rename or prefix their fields.
+fn reject_unsupported(input: &CompositeInput) -> Result<()> {
+    input.reject_object_keywords()?;
+    input.reject_lifetimes()?;
+    if let Some(composable) = input
+        .composables
+        .iter()
+        .find(|composable| !composable.only_renames())
+    {
+        return Err(Error::new(
+            composable.ty.span(),
+            "members of composite input objects can only be renamed or prefixed: they can't be \
+             conditionally compiled, listed as `from_context` or have fields excluded, selected \
+             or aliased",
+        ));
+    }
+    Ok(())
+}
+
+/// Builds the `GraphQLType` impl of the composite, merging the input fields of its members after
+/// checking that they don't register different types of the same name. Without type info, the
+/// check only runs the first time on each thread, like the fields of composite objects are only
+/// merged once.
+fn expand_impl_graphql_type(input: &CompositeInput) -> TokenStream {
+    let name = &input.ident;
+    let name_lit = input.name_lit();
+    let scalar = input.scalar();
+    let (impl_generics, bounds) = input.impl_generics();
+    let description = input.description();
+    let composables = input
+        .composables
+        .iter()
+        .map(|composable| &composable.ty)
+        .collect::<Vec<_>>();
+    let descriptors =
+        expand_member_descriptors(input, ComposableKind::InputObject, &input.scalar());
+    let register_types = expand_register_types(input);
+    let check_types = if input.has_info() {
+        quote! { #register_types }
+    } else {
+        let static_self_ty = input.to_static(&input.self_ty());
+        quote! {
+            ::juniper_compose::cache::check_types_once::<#static_self_ty, #scalar>(|| {
+                #register_types
+            });
+        }
+    };
+    quote! {
+        impl #impl_generics ::juniper::GraphQLType<#scalar> for #name
+        where
+            #bounds
+        {
+            fn name(info: &Self::TypeInfo) -> ::std::option::Option<&str> {
+                ::std::option::Option::Some(#name_lit)
+            }
+
+            fn meta<'__r>(
+                info: &Self::TypeInfo,
+                registry: &mut ::juniper::executor::Registry<'__r, #scalar>
+            ) -> ::juniper::meta::MetaType<'__r, #scalar>
+            where
+                #scalar: '__r
+            {
+                #check_types
+
+                let mut fields = ::std::vec![];
+                #(
+                    let member = #descriptors;
+                    let ::juniper::meta::MetaType::InputObject(composable_meta) =
+                        <#composables as ::juniper::GraphQLType<#scalar>>::meta(info, registry)
+                    else {
+                        ::std::panic!("`{}` is not an input object", member.type_name);
+                    };
+                    for &field_name in member.fields {
+                        let composable_field = composable_meta
+                            .input_fields
+                            .iter()
+                            .find(|field| field.name == field_name)
+                            .unwrap_or_else(|| {
+                                ::std::panic!(
+                                    "Incorrect implementation of ComposableInputObject on type {}: unknown field {}",
+                                    member.type_name,
+                                    field_name
+                                )
+                            });
+                        fields.push(::juniper::meta::Argument {
+                            name: member.exposed_name(field_name).to_string(),
+                            description: composable_field.description.clone(),
+                            arg_type: ::juniper_compose::type_to_owned(&composable_field.arg_type),
+                            default_value: composable_field.default_value.clone(),
+                        });
+                    }
+                )*
+                registry.build_input_object_type::<Self>(info, &fields)#description.into_meta()
+            }
+        }
+    }
+}
+
+fn expand_impl_graphql_value(input: &CompositeInput) -> TokenStream {
+    let name = &input.ident;
+    let context = input.context();
+    let info = input.info();
+    let scalar = input.scalar();
+    let (impl_generics, bounds) = input.impl_generics();
+    quote! {
+        impl #impl_generics ::juniper::GraphQLValue<#scalar> for #name
+        where
+            #bounds
+        {
+            type Context = #context;
+            type TypeInfo = #info;
+
+            fn type_name<'__i>(&self, info: &'__i Self::TypeInfo) -> Option<&'__i str> {
+                <Self as ::juniper::GraphQLType<#scalar>>::name(info)
+            }
+        }
+
+        impl #impl_generics ::juniper::GraphQLValueAsync<#scalar> for #name
+        where
+            #bounds
+            #scalar: ::std::marker::Send + ::std::marker::Sync,
+            Self: ::std::marker::Sync,
+            Self::TypeInfo: ::std::marker::Sync,
+            Self::Context: ::std::marker::Sync,
+        {
+        }
+    }
+}
+
+/// Builds each member from the fields of the input value it defines, under the names the member
+/// gives them. Fields no member defines are left out, like juniper does.
+fn expand_impl_from_input_value(input: &CompositeInput) -> TokenStream {
+    let name = &input.ident;
+    let scalar = input.scalar();
+    let (impl_generics, bounds) = input.impl_generics();
+    let composables = input
+        .composables
+        .iter()
+        .map(|composable| &composable.ty)
+        .collect::<Vec<_>>();
+    let dispatch_table = expand_dispatch_table(input, ComposableKind::InputObject, &input.scalar());
+    let indices = expand_member_indices(input);
+    let member_fields = (0..input.composables.len())
+        .map(|index| format_ident!("member_fields_{}", index))
+        .collect::<Vec<_>>();
+    quote! {
+        impl #impl_generics ::juniper::FromInputValue<#scalar> for #name
+        where
+            #bounds
+        {
+            type Error = ::juniper::FieldError<#scalar>;
+
+            fn from_input_value(
+                value: &::juniper::InputValue<#scalar>,
+            ) -> ::std::result::Result<Self, Self::Error> {
+                let object = value.to_object_value().ok_or_else(|| {
+                    ::juniper::FieldError::<#scalar>::from(::std::format!(
+                        "Expected input object, found: {}",
+                        value,
+                    ))
+                })?;
+                #( let mut #member_fields = ::std::vec::Vec::new(); )*
+                for (field_name, field_value) in object {
+                    match #dispatch_table.find(field_name) {
+                        #(
+                            ::std::option::Option::Some((#indices, field_name)) => {
+                                #member_fields.push((field_name, ::std::clone::Clone::clone(field_value)));
+                            }
+                        )*
+                        _ => {}
+                    }
+                }
+                ::std::result::Result::Ok(Self(#(
+                    <#composables as ::juniper::FromInputValue<#scalar>>::from_input_value(
+                        &::juniper::InputValue::object(#member_fields.into_iter().collect()),
+                    )
+                    .map_err(::juniper::IntoFieldError::into_field_error)?
+                ),*))
+            }
+        }
+    }
+}
+
+/// Merges the input values of the members, under the names the composite exposes their fields
+/// under.
+fn expand_impl_to_input_value(input: &CompositeInput) -> TokenStream {
+    let name = &input.ident;
+    let scalar = input.scalar();
+    let (impl_generics, bounds) = input.impl_generics();
+    let descriptors =
+        expand_member_descriptors(input, ComposableKind::InputObject, &input.scalar());
+    let indices = (0..input.composables.len()).map(Index::from);
+    quote! {
+        impl #impl_generics ::juniper::ToInputValue<#scalar> for #name
+        where
+            #bounds
+        {
+            fn to_input_value(&self) -> ::juniper::InputValue<#scalar> {
+                let mut fields = ::std::vec::Vec::new();
+                #(
+                    let member = #descriptors;
+                    if let ::juniper::InputValue::Object(values) =
+                        ::juniper::ToInputValue::<#scalar>::to_input_value(&self.#indices)
+                    {
+                        fields.extend(values.into_iter().map(|(field_name, field_value)| {
+                            let exposed_name = member
+                                .fields
+                                .iter()
+                                .find(|&&field| field == field_name.item)
+                                .map_or(field_name.item, |&field| member.exposed_name(field).to_string());
+                            (exposed_name, field_value.item)
+                        }));
+                    }
+                )*
+                ::juniper::InputValue::object(fields.into_iter().collect())
+            }
+        }
+    }
+}
+
+/// Implements the marker and reflection traits juniper requires of argument types.
+fn expand_impl_marker_traits(input: &CompositeInput) -> TokenStream {
+    let name = &input.ident;
+    let name_lit = input.name_lit();
+    let scalar = input.scalar();
+    let (impl_generics, bounds) = input.impl_generics();
+    let composables = input.composables.iter().map(|composable| &composable.ty);
+    quote! {
+        impl #impl_generics ::juniper::marker::IsInputType<#scalar> for #name
+        where
+            #bounds
+        {
+            fn mark() {
+                #( <#composables as ::juniper::marker::IsInputType<#scalar>>::mark(); )*
+            }
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::BaseType<#scalar> for #name
+        where
+            #bounds
+        {
+            const NAME: ::juniper::macros::reflect::Type = #name_lit;
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::BaseSubTypes<#scalar> for #name
+        where
+            #bounds
+        {
+            const NAMES: ::juniper::macros::reflect::Types =
+                &[<Self as ::juniper::macros::reflect::BaseType<#scalar>>::NAME];
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::WrappedType<#scalar> for #name
+        where
+            #bounds
+        {
+            const VALUE: ::juniper::macros::reflect::WrappedValue = 1;
+        }
+    }
+}
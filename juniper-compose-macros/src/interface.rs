@@ -0,0 +1,384 @@
+use crate::{
+    composable::ComposableKind,
+    composite::{
+        CompositeInput, expand_assert_composable, expand_assert_context, expand_assert_disjoint,
+        expand_assert_has_fields, expand_impl_composable, expand_impl_field_count,
+        expand_impl_graphql_type,
+    },
+};
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{
+    Error, Path, Result, Token, parenthesized, parse::Parse, parse_quote, punctuated::Punctuated,
+    spanned::Spanned, token::Comma,
+};
+
+/// Input of the `composite_interface!` macro: a composite followed by `for = (...)`.
+pub struct CompositeInterfaceInput {
+    composite: CompositeInput,
+    implementers: Punctuated<Path, Comma>,
+}
+
+impl Parse for CompositeInterfaceInput {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let mut composite = input.parse::<CompositeInput>()?;
+        composite.make_scalar_generic()?;
+        composite.reject_object_keywords()?;
+        composite.reject_lifetimes()?;
+        composite.reject_rename_all()?;
+        if let Some(composable) = composite
+            .composables
+            .iter()
+            .find(|composable| !composable.is_plain())
+        {
+            return Err(Error::new(
+                composable.ty.span(),
+                "interface fields are resolved by the implementers, so they can't be renamed, \
+                 prefixed or excluded, and `from_context` has no effect",
+            ));
+        }
+        input.parse::<Token![for]>()?;
+        input.parse::<Token![=]>()?;
+        let content;
+        let paren = parenthesized!(content in input);
+        let implementers = content.parse_terminated(Path::parse, Comma)?;
+        if implementers.is_empty() {
+            return Err(Error::new(
+                paren.span.join(),
+                "expected at least one implementer",
+            ));
+        }
+        // The `where` clause goes last, though the members may be followed by one as well.
+        if composite.where_clause.is_none() {
+            composite.where_clause = input.parse()?;
+        }
+        if composite.has_generic_scalar() {
+            bound_implementers(&mut composite, &implementers);
+        }
+        Ok(Self {
+            composite,
+            implementers,
+        })
+    }
+}
+
+/// A generic interface is implemented for the scalar value types all its implementers are, as
+/// composite objects are only implemented for one.
+fn bound_implementers(composite: &mut CompositeInput, implementers: &Punctuated<Path, Comma>) {
+    let scalar = composite.scalar();
+    let context = composite.context();
+    let info = composite.info();
+    let where_clause = composite
+        .where_clause
+        .get_or_insert_with(|| parse_quote! { where });
+    for implementer in implementers {
+        where_clause.predicates.push(parse_quote! {
+            #implementer: ::juniper::GraphQLType<#scalar, Context = #context, TypeInfo = #info>
+                + ::juniper::marker::IsOutputType<#scalar>
+                + ::juniper::marker::GraphQLObject<#scalar>
+                + ::juniper::macros::reflect::BaseType<#scalar>
+        });
+    }
+}
+
+impl CompositeInterfaceInput {
+    /// Returns the implementers along with the name of the enum variant holding each of them.
+    fn variants(&self) -> impl Iterator<Item = (&syn::Ident, &Path)> {
+        self.implementers.iter().filter_map(|implementer| {
+            implementer
+                .segments
+                .last()
+                .map(|segment| (&segment.ident, implementer))
+        })
+    }
+}
+
+pub fn expand_composite_interface(input: &CompositeInterfaceInput) -> TokenStream {
+    let composite = &input.composite;
+    let assert_composable = expand_assert_composable(composite, ComposableKind::Interface);
+    let attrs = &composite.attrs;
+    let vis = &composite.vis;
+    let name = &composite.ident;
+    let variants = input.variants().map(|(variant, implementer)| {
+        quote! { #variant(#implementer) }
+    });
+    let from_impls = input.variants().map(|(variant, implementer)| {
+        quote! {
+            impl ::std::convert::From<#implementer> for #name {
+                fn from(value: #implementer) -> Self {
+                    Self::#variant(value)
+                }
+            }
+        }
+    });
+    let implementers = input.implementers.iter().cloned().collect::<Vec<_>>();
+    let impl_graphql_type =
+        expand_impl_graphql_type(composite, ComposableKind::Interface, &implementers);
+    let impl_graphql_value = expand_impl_graphql_value(input);
+    let impl_graphql_value_async = expand_impl_graphql_value_async(input);
+    let impl_marker_traits = expand_impl_marker_traits(input);
+    let impl_composable = expand_impl_composable(composite, ComposableKind::Interface);
+    let impl_field_count = expand_impl_field_count(composite, ComposableKind::Interface);
+    let assert_has_fields = expand_assert_has_fields(composite, ComposableKind::Interface);
+    let assert_disjoint = expand_assert_disjoint(composite, ComposableKind::Interface);
+    let assert_context = expand_assert_context(composite);
+    let assert_implemented = expand_assert_implemented(input);
+    quote! {
+        #assert_composable
+        #( #attrs )*
+        #vis enum #name {
+            #( #variants ),*
+        }
+        #( #from_impls )*
+        #impl_graphql_type
+        #impl_graphql_value
+        #impl_graphql_value_async
+        #impl_marker_traits
+        #impl_composable
+        #impl_field_count
+        #assert_has_fields
+        #assert_disjoint
+        #assert_context
+        #assert_implemented
+    }
+}
+
+fn expand_impl_graphql_value(input: &CompositeInterfaceInput) -> TokenStream {
+    let composite = &input.composite;
+    let name = &composite.ident;
+    let context = composite.context();
+    let info = composite.info();
+    let scalar = composite.scalar();
+    let (impl_generics, bounds) = composite.impl_generics();
+    let resolve_field = input.variants().map(|(variant, implementer)| {
+        quote! {
+            Self::#variant(value) => <#implementer as ::juniper::GraphQLValue<#scalar>>::resolve_field(
+                value,
+                info,
+                field_name,
+                arguments,
+                executor
+            ),
+        }
+    });
+    let concrete_type_name = input.variants().map(|(variant, implementer)| {
+        quote! {
+            Self::#variant(value) => <#implementer as ::juniper::GraphQLValue<#scalar>>::concrete_type_name(
+                value,
+                context,
+                info
+            ),
+        }
+    });
+    let resolve_into_type = input.variants().map(|(variant, _)| {
+        quote! {
+            Self::#variant(value) => executor.resolve(info, value),
+        }
+    });
+    quote! {
+        impl #impl_generics ::juniper::GraphQLValue<#scalar> for #name
+        where
+            #bounds
+        {
+            type Context = #context;
+            type TypeInfo = #info;
+
+            fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+                <Self as ::juniper::GraphQLType<#scalar>>::name(info)
+            }
+
+            fn resolve_field(
+                &self,
+                info: &Self::TypeInfo,
+                field_name: &str,
+                arguments: &::juniper::Arguments<'_, #scalar>,
+                executor: &::juniper::executor::Executor<'_, '_, Self::Context, #scalar>
+            ) -> ::juniper::executor::ExecutionResult<#scalar> {
+                match self {
+                    #( #resolve_field )*
+                }
+            }
+
+            fn concrete_type_name(
+                &self,
+                context: &Self::Context,
+                info: &Self::TypeInfo
+            ) -> String {
+                match self {
+                    #( #concrete_type_name )*
+                }
+            }
+
+            fn resolve_into_type(
+                &self,
+                info: &Self::TypeInfo,
+                type_name: &str,
+                selection_set: ::std::option::Option<&[::juniper::Selection<'_, #scalar>]>,
+                executor: &::juniper::executor::Executor<'_, '_, Self::Context, #scalar>
+            ) -> ::juniper::executor::ExecutionResult<#scalar> {
+                match self {
+                    #( #resolve_into_type )*
+                }
+            }
+        }
+    }
+}
+
+fn expand_impl_graphql_value_async(input: &CompositeInterfaceInput) -> TokenStream {
+    let composite = &input.composite;
+    let name = &composite.ident;
+    let scalar = composite.scalar();
+    let (impl_generics, bounds) = composite.impl_generics();
+    let resolve_field = input.variants().map(|(variant, implementer)| {
+        quote! {
+            Self::#variant(value) => <#implementer as ::juniper::GraphQLValueAsync<#scalar>>::resolve_field_async(
+                value,
+                info,
+                field_name,
+                arguments,
+                executor
+            ),
+        }
+    });
+    let resolve_into_type = input.variants().map(|(variant, _)| {
+        quote! {
+            Self::#variant(value) => ::std::boxed::Box::pin(executor.resolve_async(info, value)),
+        }
+    });
+    let implementer_bounds = composite.has_generic_scalar().then(|| {
+        let implementers = input.implementers.iter().collect::<Vec<_>>();
+        quote! { #( #implementers: ::juniper::GraphQLValueAsync<#scalar>, )* }
+    });
+    quote! {
+        impl #impl_generics ::juniper::GraphQLValueAsync<#scalar> for #name
+        where
+            #bounds
+            #scalar: ::std::marker::Send + ::std::marker::Sync,
+            Self::TypeInfo: Sync,
+            Self::Context: Sync,
+            #implementer_bounds
+        {
+            fn resolve_field_async<'a>(
+                &'a self,
+                info: &'a Self::TypeInfo,
+                field_name: &'a str,
+                arguments: &'a ::juniper::Arguments<'_, #scalar>,
+                executor: &'a ::juniper::executor::Executor<'_, '_, Self::Context, #scalar>
+            ) -> ::juniper::BoxFuture<'a, ::juniper::executor::ExecutionResult<#scalar>> {
+                match self {
+                    #( #resolve_field )*
+                }
+            }
+
+            fn resolve_into_type_async<'a>(
+                &'a self,
+                info: &'a Self::TypeInfo,
+                type_name: &str,
+                selection_set: ::std::option::Option<&'a [::juniper::Selection<'a, #scalar>]>,
+                executor: &'a ::juniper::executor::Executor<'a, 'a, Self::Context, #scalar>
+            ) -> ::juniper::BoxFuture<'a, ::juniper::executor::ExecutionResult<#scalar>> {
+                match self {
+                    #( #resolve_into_type )*
+                }
+            }
+        }
+    }
+}
+
+/// Implements the marker and reflection traits juniper requires of the types listed in
+/// `#[graphql_object(impl = ...)]`.
+fn expand_impl_marker_traits(input: &CompositeInterfaceInput) -> TokenStream {
+    let composite = &input.composite;
+    let name = &composite.ident;
+    let name_lit = composite.name_lit();
+    let scalar = composite.scalar();
+    let const_scalar = composite.const_scalar();
+    let (impl_generics, bounds) = composite.impl_generics();
+    let implementers = input.implementers.iter().collect::<Vec<_>>();
+    let composable_trait = ComposableKind::Interface.trait_path();
+    quote! {
+        impl #impl_generics ::juniper::marker::IsOutputType<#scalar> for #name
+        where
+            #bounds
+        {
+            fn mark() {
+                #( <#implementers as ::juniper::marker::IsOutputType<#scalar>>::mark(); )*
+                ::juniper::assert_interfaces_impls!(#const_scalar, #name, #( #implementers ),*);
+            }
+        }
+
+        impl #impl_generics ::juniper::marker::GraphQLInterface<#scalar> for #name
+        where
+            #bounds
+        {
+            fn mark() {
+                #( <#implementers as ::juniper::marker::GraphQLObject<#scalar>>::mark(); )*
+            }
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::BaseType<#scalar> for #name
+        where
+            #bounds
+        {
+            const NAME: ::juniper::macros::reflect::Type = #name_lit;
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::BaseSubTypes<#scalar> for #name
+        where
+            #bounds
+        {
+            const NAMES: ::juniper::macros::reflect::Types = &[
+                <Self as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
+                #( <#implementers as ::juniper::macros::reflect::BaseType<#scalar>>::NAME ),*
+            ];
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::Fields<#scalar> for #name
+        where
+            #bounds
+        {
+            const NAMES: ::juniper::macros::reflect::Names =
+                <Self as #composable_trait<#scalar>>::FIELDS;
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::Implements<#scalar> for #name
+        where
+            #bounds
+        {
+            const NAMES: ::juniper::macros::reflect::Types = &[];
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::WrappedType<#scalar> for #name
+        where
+            #bounds
+        {
+            const VALUE: ::juniper::macros::reflect::WrappedValue = 1;
+        }
+    }
+}
+
+/// Asserts at compile time that every implementer defines the fields of every member.
+fn expand_assert_implemented(input: &CompositeInterfaceInput) -> TokenStream {
+    let composite = &input.composite;
+    let scalar = composite.const_scalar();
+    let composable_trait = ComposableKind::Interface.trait_path();
+    let assertions = input.implementers.iter().flat_map(|implementer| {
+        let scalar = &scalar;
+        let composable_trait = &composable_trait;
+        composite.composables.iter().map(move |composable| {
+            let ty = &composable.ty;
+            let member =
+                composable.descriptor(&quote! { <#ty as #composable_trait<#scalar>>::FIELDS });
+            let cfg = &composable.cfg;
+            quote_spanned! {implementer.span()=>
+                #( #cfg )*
+                const _: () = ::juniper_compose::check::assert_implemented(
+                    &#member,
+                    <#implementer as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
+                    <#implementer as ::juniper::macros::reflect::Fields<#scalar>>::NAMES,
+                );
+            }
+        })
+    });
+    quote! { #( #assertions )* }
+}
@@ -0,0 +1,143 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Attribute, Error, Ident, Result, Token, Type, Visibility, braced, ext::IdentExt, parse::Parse,
+    parse_quote, token::Comma,
+};
+
+/// Input of the `composite_root_node!` macro: the schema type followed by its root types.
+pub struct RootNodeInput {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    query: Type,
+    /// Mutation type, `juniper::EmptyMutation` if not given.
+    mutation: Option<Type>,
+    /// Subscription type, `juniper::EmptySubscription` if not given.
+    subscription: Option<Type>,
+    /// Context of the empty mutation and subscription types.
+    context: Option<Type>,
+    scalar: Option<Type>,
+}
+
+/// `key: Type` entry of the root types block.
+struct RootType {
+    key: Ident,
+    ty: Type,
+}
+
+impl Parse for RootType {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let key = input.call(Ident::parse_any)?;
+        input.parse::<Token![:]>()?;
+        Ok(Self {
+            key,
+            ty: input.parse()?,
+        })
+    }
+}
+
+impl Parse for RootNodeInput {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let ident = input.parse::<Ident>()?;
+        let entries;
+        braced!(entries in input);
+        let root_types = entries.parse_terminated(RootType::parse, Comma)?;
+
+        let (mut query, mut mutation, mut subscription, mut context, mut scalar) =
+            (None, None, None, None, None);
+        for RootType { key, ty } in root_types {
+            let slot = match key.to_string().as_str() {
+                "query" => &mut query,
+                "mutation" => &mut mutation,
+                "subscription" => &mut subscription,
+                "context" => &mut context,
+                "scalar" => &mut scalar,
+                _ => {
+                    return Err(Error::new(
+                        key.span(),
+                        "expected `query`, `mutation`, `subscription`, `context` or `scalar`",
+                    ));
+                }
+            };
+            if slot.replace(ty).is_some() {
+                return Err(Error::new(key.span(), format!("duplicate `{key}`")));
+            }
+        }
+        let Some(query) = query else {
+            return Err(Error::new(ident.span(), "missing `query`"));
+        };
+        Ok(Self {
+            attrs,
+            vis,
+            ident,
+            query,
+            mutation,
+            subscription,
+            context,
+            scalar,
+        })
+    }
+}
+
+/// Defines the schema type, which wraps the `juniper::RootNode` of the root types constructed
+/// with `Default` and dereferences to it.
+pub fn expand_composite_root_node(input: &RootNodeInput) -> TokenStream {
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+    let ident = &input.ident;
+    let context = input.context.clone().unwrap_or_else(|| parse_quote! { () });
+    let query = &input.query;
+    let mutation = input
+        .mutation
+        .clone()
+        .unwrap_or_else(|| parse_quote! { ::juniper::EmptyMutation<#context> });
+    let subscription = input
+        .subscription
+        .clone()
+        .unwrap_or_else(|| parse_quote! { ::juniper::EmptySubscription<#context> });
+    let scalar = input
+        .scalar
+        .clone()
+        .unwrap_or_else(|| parse_quote! { ::juniper::DefaultScalarValue });
+    let root_types = [query, &mutation, &subscription];
+    let root_node = quote! {
+        ::juniper::RootNode<'static, #( #root_types, )* #scalar>
+    };
+    quote! {
+        #( #attrs )*
+        #vis struct #ident(#root_node);
+
+        impl #ident {
+            /// Builds the schema, constructing its root types with `Default`.
+            #[must_use]
+            #vis fn new() -> Self {
+                Self(::juniper::RootNode::new_with_scalar_value(
+                    #( <#root_types as ::std::default::Default>::default(), )*
+                ))
+            }
+
+            /// Returns the `RootNode` of the schema.
+            #[must_use]
+            #vis fn into_inner(self) -> #root_node {
+                self.0
+            }
+        }
+
+        impl ::std::default::Default for #ident {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl ::std::ops::Deref for #ident {
+            type Target = #root_node;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use crate::composable::{is_attribute, object_context_and_scalar};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+use std::collections::HashMap;
+use syn::{
+    Attribute, Error, Expr, ExprLit, Ident, ImplItem, ImplItemFn, ItemImpl, Lit, LitStr, Meta,
+    MetaNameValue, Result, Token, Type, Visibility, parenthesized, parse::Parse, parse_quote,
+    spanned::Spanned, token::Comma,
+};
+
+/// Input of the `composable_split!` macro: the composite object and the members its methods are
+/// split into, followed by the `#[graphql_object]` impl being split.
+pub struct SplitInput {
+    vis: Visibility,
+    ident: Ident,
+    groups: Vec<SplitGroup>,
+    item_impl: ItemImpl,
+}
+
+/// `Member(method, ...)` entry: a composable object and the methods of the impl it takes.
+struct SplitGroup {
+    ident: Ident,
+    methods: Vec<Ident>,
+}
+
+impl Parse for SplitGroup {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let ident = input.parse::<Ident>()?;
+        let methods;
+        parenthesized!(methods in input);
+        let methods = methods.parse_terminated(Ident::parse, Comma)?;
+        if methods.is_empty() {
+            return Err(Error::new(
+                ident.span(),
+                "expected at least one method for this member",
+            ));
+        }
+        Ok(Self {
+            ident,
+            methods: methods.into_iter().collect(),
+        })
+    }
+}
+
+impl Parse for SplitInput {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let vis = input.parse()?;
+        let ident = input.parse::<Ident>()?;
+        let groups;
+        parenthesized!(groups in input);
+        let groups = groups
+            .parse_terminated(SplitGroup::parse, Comma)?
+            .into_iter()
+            .collect::<Vec<_>>();
+        if groups.is_empty() {
+            return Err(Error::new(ident.span(), "expected at least one member"));
+        }
+        input.parse::<Token![;]>()?;
+        let item_impl = input.parse::<ItemImpl>()?;
+        let self_ident = match &*item_impl.self_ty {
+            Type::Path(path) if path.qself.is_none() => path.path.get_ident(),
+            _ => None,
+        };
+        if self_ident != Some(&ident) {
+            return Err(Error::new(
+                item_impl.self_ty.span(),
+                format!("expected an impl of `{ident}`"),
+            ));
+        }
+        if item_impl.trait_.is_some() || !item_impl.generics.params.is_empty() {
+            return Err(Error::new(
+                item_impl.impl_token.span(),
+                "only inherent impls without generic parameters can be split",
+            ));
+        }
+        Ok(Self {
+            vis,
+            ident,
+            groups,
+            item_impl,
+        })
+    }
+}
+
+/// Assigns every method of the impl to the member listing it, in the order the members are
+/// listed. Each method must be listed exactly once.
+fn assign_methods(input: &SplitInput) -> Result<Vec<Vec<&ImplItemFn>>> {
+    let mut methods = HashMap::new();
+    for item in &input.item_impl.items {
+        let ImplItem::Fn(method) = item else {
+            return Err(Error::new(item.span(), "only methods can be split"));
+        };
+        methods.insert(method.sig.ident.to_string(), (method, false));
+    }
+    let mut assigned = Vec::with_capacity(input.groups.len());
+    for group in &input.groups {
+        let mut group_methods = Vec::with_capacity(group.methods.len());
+        for name in &group.methods {
+            match methods.get_mut(&name.to_string()) {
+                Some((_, true)) => {
+                    return Err(Error::new(
+                        name.span(),
+                        format!("method `{name}` is already assigned to a member"),
+                    ));
+                }
+                Some((method, taken)) => {
+                    *taken = true;
+                    group_methods.push(*method);
+                }
+                None => {
+                    return Err(Error::new(
+                        name.span(),
+                        format!("no method `{name}` in the impl of `{}`", input.ident),
+                    ));
+                }
+            }
+        }
+        assigned.push(group_methods);
+    }
+    let unassigned = input
+        .item_impl
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            ImplItem::Fn(method) if !methods[&method.sig.ident.to_string()].1 => Some(method),
+            _ => None,
+        })
+        .map(|method| Error::new(method.sig.ident.span(), "method isn't assigned to a member"))
+        .reduce(|mut errors, error| {
+            errors.combine(error);
+            errors
+        });
+    match unassigned {
+        Some(errors) => Err(errors),
+        None => Ok(assigned),
+    }
+}
+
+/// `name = "..."` and `description = "..."` arguments of the `#[graphql_object(...)]` attribute
+/// of the split impl, which name and describe the composite object rather than its members.
+#[derive(Default)]
+struct TypeArgs {
+    name: Option<LitStr>,
+    description: Option<LitStr>,
+}
+
+/// Removes the `name` and `description` arguments from the `#[graphql_object(...)]` attribute
+/// among `attrs`, returning the attributes left and the arguments removed.
+fn take_type_args(attrs: Vec<&Attribute>) -> (Vec<Attribute>, TypeArgs) {
+    let mut args = TypeArgs::default();
+    let attrs = attrs
+        .into_iter()
+        .map(|attr| {
+            let Meta::List(list) = &attr.meta else {
+                return attr.clone();
+            };
+            if !is_attribute(attr, "graphql_object") {
+                return attr.clone();
+            }
+            let mut kept = Vec::new();
+            for arg in split_args(list.tokens.clone()) {
+                match syn::parse2::<MetaNameValue>(arg.clone()) {
+                    Ok(MetaNameValue {
+                        path,
+                        value:
+                            Expr::Lit(ExprLit {
+                                lit: Lit::Str(value),
+                                ..
+                            }),
+                        ..
+                    }) if path.is_ident("name") || path.is_ident("description") => {
+                        if path.is_ident("name") {
+                            args.name = Some(value);
+                        } else {
+                            args.description = Some(value);
+                        }
+                    }
+                    _ => kept.push(arg),
+                }
+            }
+            let path = list.path.clone();
+            if kept.is_empty() {
+                parse_quote! { #[#path] }
+            } else {
+                parse_quote! { #[#path(#( #kept ),*)] }
+            }
+        })
+        .collect();
+    (attrs, args)
+}
+
+/// Splits the arguments of an attribute at the commas separating them.
+fn split_args(tokens: TokenStream) -> Vec<TokenStream> {
+    let mut args = vec![TokenStream::new()];
+    for token in tokens {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => args.push(TokenStream::new()),
+            _ => args.last_mut().unwrap().extend([token]),
+        }
+    }
+    args.retain(|arg| !arg.is_empty());
+    args
+}
+
+/// Splits the impl into one composable object per member, and composes them into the composite
+/// object in its place, with the context and scalar value type of the impl. Doc comments of the
+/// impl describe the composite object, and so do the `name` and `description` arguments of its
+/// `#[graphql_object(...)]` attribute, which become the name and the description of the composite
+/// object. Its other attributes are repeated on every member impl.
+pub fn expand_composable_split(input: &SplitInput) -> TokenStream {
+    let (assigned, (context, scalar)) = match assign_methods(input)
+        .and_then(|assigned| Ok((assigned, object_context_and_scalar(&input.item_impl.attrs)?)))
+    {
+        Ok(expanded) => expanded,
+        Err(error) => return error.into_compile_error(),
+    };
+    let params = [
+        context.map(|context| quote! { Context = #context }),
+        scalar.map(|scalar| quote! { ScalarValue = #scalar }),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+    let params = (!params.is_empty()).then(|| quote! { <#( #params ),*> });
+    let vis = &input.vis;
+    let name = &input.ident;
+    let (docs, attrs): (Vec<&Attribute>, Vec<&Attribute>) = input
+        .item_impl
+        .attrs
+        .iter()
+        .partition(|attr| attr.path().is_ident("doc"));
+    let (attrs, type_args) = take_type_args(attrs);
+    let graphql_name = type_args.name.map(|name| quote! { as #name });
+    let description = type_args
+        .description
+        .map(|description| quote! { #[graphql(description = #description)] });
+    let members = input
+        .groups
+        .iter()
+        .map(|group| &group.ident)
+        .collect::<Vec<_>>();
+    let impls = members.iter().zip(&assigned).map(|(member, methods)| {
+        let doc = format!(" Fields of [`{name}`] split from its impl.");
+        quote! {
+            #[doc = #doc]
+            #[derive(::std::default::Default)]
+            #vis struct #member;
+
+            #[::juniper_compose::composable_object]
+            #( #attrs )*
+            impl #member {
+                #( #methods )*
+            }
+        }
+    });
+    quote! {
+        #( #impls )*
+
+        ::juniper_compose::composite_object!(
+            #( #docs )*
+            #description
+            #vis #name #graphql_name #params(#( #members ),*)
+        );
+    }
+}
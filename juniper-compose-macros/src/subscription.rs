@@ -0,0 +1,145 @@
+use crate::{
+    composable::ComposableKind,
+    composite::{
+        CompositeInput, expand_assert_composable, expand_assert_context, expand_assert_disjoint,
+        expand_assert_has_fields, expand_definition, expand_dispatch_table, expand_impl_composable,
+        expand_impl_debug, expand_impl_field_count, expand_impl_field_origin,
+        expand_impl_graphql_type, expand_member_cfgs, expand_member_constructors,
+        expand_member_indices,
+    },
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+pub fn expand_composite_subscription(input: &CompositeInput) -> TokenStream {
+    if let Err(error) = input.reject_object_keywords() {
+        return error.into_compile_error();
+    }
+    let assert_composable = expand_assert_composable(input, ComposableKind::Subscription);
+    let definition = expand_definition(input);
+    let impl_debug = expand_impl_debug(input, ComposableKind::Subscription);
+    let impl_graphql_type = expand_impl_graphql_type(input, ComposableKind::Subscription, &[]);
+    let impl_graphql_value = expand_impl_graphql_value(input);
+    let impl_graphql_subscription_value = expand_impl_graphql_subscription_value(input);
+    let impl_composable = expand_impl_composable(input, ComposableKind::Subscription);
+    let assert_disjoint = expand_assert_disjoint(input, ComposableKind::Subscription);
+    let impl_field_origin = expand_impl_field_origin(input, ComposableKind::Subscription);
+    let impl_field_count = expand_impl_field_count(input, ComposableKind::Subscription);
+    let assert_has_fields = expand_assert_has_fields(input, ComposableKind::Subscription);
+    let assert_context = expand_assert_context(input);
+    quote! {
+        #assert_composable
+        #definition
+        #impl_debug
+        #impl_graphql_type
+        #impl_graphql_value
+        #impl_graphql_subscription_value
+        #impl_composable
+        #impl_field_origin
+        #impl_field_count
+        #assert_has_fields
+        #assert_disjoint
+        #assert_context
+    }
+}
+
+fn expand_impl_graphql_value(input: &CompositeInput) -> TokenStream {
+    let self_ty = input.self_ty();
+    let name_lit = input.name_lit();
+    let context = input.context();
+    let info = input.info();
+    let scalar = input.scalar();
+    let (impl_generics, bounds) = input.impl_generics();
+    quote! {
+        impl #impl_generics ::juniper::GraphQLValue<#scalar> for #self_ty
+        where
+            #bounds
+        {
+            type Context = #context;
+            type TypeInfo = #info;
+
+            fn type_name<'__i>(&self, info: &'__i Self::TypeInfo) -> Option<&'__i str> {
+                <Self as ::juniper::GraphQLType<#scalar>>::name(info)
+            }
+
+            fn resolve_field(
+                &self,
+                info: &Self::TypeInfo,
+                field_name: &str,
+                arguments: &::juniper::Arguments<'_, #scalar>,
+                executor: &::juniper::executor::Executor<'_, '_, Self::Context, #scalar>
+            ) -> ::juniper::executor::ExecutionResult<#scalar> {
+                Err(::juniper::FieldError::from(
+                    "Called `resolve_field` on subscription object",
+                ))
+            }
+
+            fn concrete_type_name(
+                &self,
+                context: &Self::Context,
+                info: &Self::TypeInfo
+            ) -> String {
+                String::from(#name_lit)
+            }
+        }
+    }
+}
+
+fn expand_impl_graphql_subscription_value(input: &CompositeInput) -> TokenStream {
+    let self_ty = input.self_ty();
+    let name_lit = input.name_lit();
+    let scalar = input.scalar();
+    let composables = input.composables.iter().map(|composable| &composable.ty);
+    let dispatch_table =
+        expand_dispatch_table(input, ComposableKind::Subscription, &input.scalar());
+    let indices = expand_member_indices(input);
+    let cfgs = expand_member_cfgs(input);
+    let constructors = expand_member_constructors(input);
+    let (impl_generics, bounds) = input.impl_generics();
+    quote! {
+        impl #impl_generics ::juniper::GraphQLSubscriptionValue<#scalar> for #self_ty
+        where
+            #bounds
+            Self::TypeInfo: Sync,
+            Self::Context: Sync,
+        {
+            fn resolve_field_into_stream<'__s, '__i, '__fi, '__args, '__e, '__ref_e, '__res, '__f>(
+                &'__s self,
+                info: &'__i Self::TypeInfo,
+                field_name: &'__fi str,
+                arguments: ::juniper::Arguments<'__args, #scalar>,
+                executor: &'__ref_e ::juniper::executor::Executor<'__ref_e, '__e, Self::Context, #scalar>
+            ) -> ::juniper::BoxFuture<'__f, ::std::result::Result<
+                ::juniper::Value<::juniper::ValuesStream<'__res, #scalar>>,
+                ::juniper::FieldError<#scalar>,
+            >>
+            where
+                '__s: '__f,
+                '__fi: '__f,
+                '__args: '__f,
+                '__ref_e: '__f,
+                '__res: '__f,
+                '__i: '__res,
+                '__e: '__res,
+            {
+                match #dispatch_table.find(field_name) {
+                    #(
+                        #cfgs
+                        ::std::option::Option::Some((#indices, field_name)) => {
+                            ::juniper_compose::dispatch::resolve_field_into_stream::<#composables, #scalar>(
+                                #constructors,
+                                info,
+                                field_name,
+                                arguments,
+                                executor
+                            )
+                        }
+                    )*
+                    _ => ::std::boxed::Box::pin(::std::future::ready(::std::result::Result::Err(
+                        ::juniper_compose::dispatch::unknown_field(#name_lit, field_name),
+                    ))),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{
+    composable::ComposableKind,
+    composite::{
+        CompositeInput, expand_assert_composable, expand_assert_context, expand_assert_disjoint,
+        expand_impl_composable,
+    },
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Ident, Result, Type, spanned::Spanned};
+
+/// Members and variants of a composite union: the enum variant holding each member is named
+/// after the last segment of its type.
+struct UnionVariant<'a> {
+    cfg: TokenStream,
+    ident: &'a Ident,
+    ty: &'a Type,
+}
+
+fn variants(input: &CompositeInput) -> Result<Vec<UnionVariant<'_>>> {
+    input
+        .composables
+        .iter()
+        .map(|composable| {
+            let ident = match &composable.ty {
+                Type::Path(path) => path.path.segments.last().map(|segment| &segment.ident),
+                _ => None,
+            };
+            let Some(ident) = ident else {
+                return Err(Error::new(
+                    composable.ty.span(),
+                    "members of composite unions must be named types",
+                ));
+            };
+            let cfg = &composable.cfg;
+            Ok(UnionVariant {
+                cfg: quote! { #( #cfg )* },
+                ident,
+                ty: &composable.ty,
+            })
+        })
+        .collect()
+}
+
+/// Union members contribute variant types rather than fields, so there is nothing to rename,
+/// prefix or select, and they are never constructed by the composite.
+fn reject_unsupported(input: &CompositeInput) -> Result<()> {
+    input.reject_object_keywords()?;
+    input.reject_lifetimes()?;
+    input.reject_rename_all()?;
+    if let Some(composable) = input
+        .composables
+        .iter()
+        .find(|composable| !composable.is_plain())
+    {
+        return Err(Error::new(
+            composable.ty.span(),
+            "members of composite unions contribute variant types, so they can't be renamed, \
+             prefixed or have fields excluded or selected, and `from_context` has no effect",
+        ));
+    }
+    Ok(())
+}
+
+pub fn expand_composite_union(input: &CompositeInput) -> TokenStream {
+    let variants = match reject_unsupported(input).and_then(|()| variants(input)) {
+        Ok(variants) => variants,
+        Err(error) => return error.into_compile_error(),
+    };
+    let assert_composable = expand_assert_composable(input, ComposableKind::Union);
+    let attrs = &input.attrs;
+    let vis = &input.vis;
+    let name = &input.ident;
+    let enum_variants = variants
+        .iter()
+        .map(|UnionVariant { cfg, ident, ty }| quote! { #cfg #ident(#ty) });
+    let from_impls = variants.iter().map(|UnionVariant { cfg, ident, ty }| {
+        quote! {
+            #cfg
+            impl ::std::convert::From<#ty> for #name {
+                fn from(value: #ty) -> Self {
+                    Self::#ident(value)
+                }
+            }
+        }
+    });
+    let impl_graphql_type = expand_impl_graphql_type(input, &variants);
+    let impl_graphql_value = expand_impl_graphql_value(input, &variants);
+    let impl_graphql_value_async = expand_impl_graphql_value_async(input, &variants);
+    let impl_marker_traits = expand_impl_marker_traits(input, &variants);
+    let impl_composable = expand_impl_composable(input, ComposableKind::Union);
+    let assert_disjoint = expand_assert_disjoint(input, ComposableKind::Union);
+    let assert_context = expand_assert_context(input);
+    quote! {
+        #assert_composable
+        #( #attrs )*
+        #vis enum #name {
+            #( #enum_variants ),*
+        }
+        #( #from_impls )*
+        #impl_graphql_type
+        #impl_graphql_value
+        #impl_graphql_value_async
+        #impl_marker_traits
+        #impl_composable
+        #assert_disjoint
+        #assert_context
+    }
+}
+
+/// Builds the `GraphQLType` impl of the composite, merging the variant types of its members.
+fn expand_impl_graphql_type(input: &CompositeInput, variants: &[UnionVariant]) -> TokenStream {
+    let name = &input.ident;
+    let name_lit = input.name_lit();
+    let scalar = input.scalar();
+    let (impl_generics, bounds) = input.impl_generics();
+    let description = input.description();
+    let members = variants.iter().map(|UnionVariant { cfg, ty, .. }| {
+        quote! {
+            #cfg
+            {
+                let ::juniper::meta::MetaType::Union(member_meta) =
+                    <#ty as ::juniper::GraphQLType<#scalar>>::meta(info, registry)
+                else {
+                    ::std::panic!("`{}` is not a union", ::std::stringify!(#ty));
+                };
+                types.extend(member_meta.of_type_names.into_iter().map(|type_name| {
+                    ::juniper::Type::NonNullNamed(::std::borrow::Cow::Owned(type_name))
+                }));
+            }
+        }
+    });
+    quote! {
+        impl #impl_generics ::juniper::GraphQLType<#scalar> for #name
+        where
+            #bounds
+        {
+            fn name(info: &Self::TypeInfo) -> ::std::option::Option<&str> {
+                ::std::option::Option::Some(#name_lit)
+            }
+
+            fn meta<'__r>(
+                info: &Self::TypeInfo,
+                registry: &mut ::juniper::executor::Registry<'__r, #scalar>
+            ) -> ::juniper::meta::MetaType<'__r, #scalar>
+            where
+                #scalar: '__r
+            {
+                let mut types = ::std::vec::Vec::new();
+                #( #members )*
+                registry.build_union_type::<Self>(info, &types)#description.into_meta()
+            }
+        }
+    }
+}
+
+/// The composite holds a value of the member that recognizes it, which resolves it.
+fn expand_impl_graphql_value(input: &CompositeInput, variants: &[UnionVariant]) -> TokenStream {
+    let name = &input.ident;
+    let context = input.context();
+    let info = input.info();
+    let scalar = input.scalar();
+    let (impl_generics, bounds) = input.impl_generics();
+    let concrete_type_name = variants.iter().map(|UnionVariant { cfg, ident, ty }| {
+        quote! {
+            #cfg
+            Self::#ident(value) => <#ty as ::juniper::GraphQLValue<#scalar>>::concrete_type_name(
+                value,
+                context,
+                info
+            ),
+        }
+    });
+    let resolve_into_type = variants.iter().map(|UnionVariant { cfg, ident, ty }| {
+        quote! {
+            #cfg
+            Self::#ident(value) => <#ty as ::juniper::GraphQLValue<#scalar>>::resolve_into_type(
+                value,
+                info,
+                type_name,
+                selection_set,
+                executor
+            ),
+        }
+    });
+    quote! {
+        impl #impl_generics ::juniper::GraphQLValue<#scalar> for #name
+        where
+            #bounds
+        {
+            type Context = #context;
+            type TypeInfo = #info;
+
+            fn type_name<'__i>(&self, info: &'__i Self::TypeInfo) -> Option<&'__i str> {
+                <Self as ::juniper::GraphQLType<#scalar>>::name(info)
+            }
+
+            fn concrete_type_name(
+                &self,
+                context: &Self::Context,
+                info: &Self::TypeInfo
+            ) -> String {
+                match self {
+                    #( #concrete_type_name )*
+                }
+            }
+
+            fn resolve_into_type(
+                &self,
+                info: &Self::TypeInfo,
+                type_name: &str,
+                selection_set: ::std::option::Option<&[::juniper::Selection<'_, #scalar>]>,
+                executor: &::juniper::executor::Executor<'_, '_, Self::Context, #scalar>
+            ) -> ::juniper::executor::ExecutionResult<#scalar> {
+                match self {
+                    #( #resolve_into_type )*
+                }
+            }
+        }
+    }
+}
+
+fn expand_impl_graphql_value_async(
+    input: &CompositeInput,
+    variants: &[UnionVariant],
+) -> TokenStream {
+    let name = &input.ident;
+    let scalar = input.scalar();
+    let (impl_generics, bounds) = input.impl_generics();
+    let resolve_into_type = variants.iter().map(|UnionVariant { cfg, ident, ty }| {
+        quote! {
+            #cfg
+            Self::#ident(value) => <#ty as ::juniper::GraphQLValueAsync<#scalar>>::resolve_into_type_async(
+                value,
+                info,
+                type_name,
+                selection_set,
+                executor
+            ),
+        }
+    });
+    quote! {
+        impl #impl_generics ::juniper::GraphQLValueAsync<#scalar> for #name
+        where
+            #bounds
+            #scalar: ::std::marker::Send + ::std::marker::Sync,
+            Self::TypeInfo: Sync,
+            Self::Context: Sync,
+        {
+            fn resolve_into_type_async<'a>(
+                &'a self,
+                info: &'a Self::TypeInfo,
+                type_name: &str,
+                selection_set: ::std::option::Option<&'a [::juniper::Selection<'a, #scalar>]>,
+                executor: &'a ::juniper::executor::Executor<'a, 'a, Self::Context, #scalar>
+            ) -> ::juniper::BoxFuture<'a, ::juniper::executor::ExecutionResult<#scalar>> {
+                match self {
+                    #( #resolve_into_type )*
+                }
+            }
+        }
+    }
+}
+
+/// Implements the marker and reflection traits juniper requires of field types. The subtypes of
+/// the union are its variant types, as listed by its `ComposableUnion` impl.
+fn expand_impl_marker_traits(input: &CompositeInput, variants: &[UnionVariant]) -> TokenStream {
+    let name = &input.ident;
+    let name_lit = input.name_lit();
+    let scalar = input.scalar();
+    let const_scalar = input.const_scalar();
+    let (impl_generics, bounds) = input.impl_generics();
+    let composable_trait = ComposableKind::Union.trait_path();
+    let cfgs = variants
+        .iter()
+        .map(|variant| &variant.cfg)
+        .collect::<Vec<_>>();
+    let members = variants
+        .iter()
+        .map(|variant| variant.ty)
+        .collect::<Vec<_>>();
+    quote! {
+        impl #impl_generics ::juniper::marker::IsOutputType<#scalar> for #name
+        where
+            #bounds
+        {
+            fn mark() {
+                #( #cfgs <#members as ::juniper::marker::IsOutputType<#scalar>>::mark(); )*
+            }
+        }
+
+        impl #impl_generics ::juniper::marker::GraphQLUnion<#scalar> for #name
+        where
+            #bounds
+        {
+            fn mark() {
+                #( #cfgs <#members as ::juniper::marker::GraphQLUnion<#scalar>>::mark(); )*
+            }
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::BaseType<#scalar> for #name
+        where
+            #bounds
+        {
+            const NAME: ::juniper::macros::reflect::Type = #name_lit;
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::BaseSubTypes<#scalar> for #name
+        where
+            #bounds
+        {
+            const NAMES: ::juniper::macros::reflect::Types = {
+                const VARIANTS: &[&str] = <#name as #composable_trait<#const_scalar>>::VARIANTS;
+                const NAMES: [&str; VARIANTS.len() + 1] = {
+                    let mut names = [#name_lit; VARIANTS.len() + 1];
+                    let mut i = 0;
+                    while i < VARIANTS.len() {
+                        names[i + 1] = VARIANTS[i];
+                        i += 1;
+                    }
+                    names
+                };
+                &NAMES
+            };
+        }
+
+        impl #impl_generics ::juniper::macros::reflect::WrappedType<#scalar> for #name
+        where
+            #bounds
+        {
+            const VALUE: ::juniper::macros::reflect::WrappedValue = 1;
+        }
+    }
+}
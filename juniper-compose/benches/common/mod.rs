@@ -0,0 +1,75 @@
+//! Composable objects shared by the benchmarks: eight members of twelve `snake_case` fields each,
+//! named like the resolvers of a real schema, so that compiling the benchmarks also exercises the
+//! compile-time checks and name conversions of composites of long names.
+
+/// Invokes `$callback!` with every member and its fields, as `Member { field, ... }`.
+macro_rules! with_members {
+    ($callback:ident) => {
+        $callback! {
+            AccountQueries {
+                account_balance_in_cents, account_created_at_timestamp,
+                account_owner_display_name, account_primary_email_address,
+                account_two_factor_enabled, account_last_login_timestamp,
+                account_subscription_plan_name, account_outstanding_invoice_count,
+                account_billing_country_code, account_referral_program_code,
+                account_notification_channel_count, account_storage_quota_in_bytes,
+            },
+            ProjectQueries {
+                project_member_count, project_open_issue_count, project_closed_issue_count,
+                project_default_branch_name, project_last_deployment_timestamp,
+                project_continuous_integration_status, project_code_coverage_percentage,
+                project_pending_review_count, project_archived_repository_count,
+                project_release_candidate_name, project_dependency_update_count,
+                project_security_advisory_count,
+            },
+            BillingQueries {
+                billing_current_period_start, billing_current_period_end,
+                billing_next_invoice_amount, billing_payment_method_brand,
+                billing_payment_method_last_four, billing_tax_identification_number,
+                billing_applied_discount_percentage, billing_failed_payment_attempt_count,
+                billing_refunded_amount_in_cents, billing_credit_balance_in_cents,
+                billing_usage_based_charge_count, billing_invoice_delivery_email_count,
+            },
+            AnalyticsQueries {
+                analytics_daily_active_user_count, analytics_weekly_active_user_count,
+                analytics_monthly_active_user_count, analytics_average_session_length_seconds,
+                analytics_bounce_rate_percentage, analytics_conversion_funnel_step_count,
+                analytics_retained_user_count_after_week, analytics_churned_user_count,
+                analytics_page_view_count, analytics_unique_visitor_count,
+                analytics_tracked_event_type_count, analytics_experiment_variant_count,
+            },
+            SupportQueries {
+                support_open_ticket_count, support_average_first_response_minutes,
+                support_escalated_ticket_count, support_customer_satisfaction_score,
+                support_pending_ticket_count, support_resolved_ticket_count_today,
+                support_assigned_agent_count, support_knowledge_base_article_count,
+                support_macro_template_count, support_reopened_ticket_count,
+                support_breached_agreement_count, support_unassigned_ticket_count,
+            },
+            InventoryQueries {
+                inventory_stock_keeping_unit_count, inventory_reserved_item_count,
+                inventory_backordered_item_count, inventory_warehouse_location_count,
+                inventory_reorder_threshold_count, inventory_damaged_item_count,
+                inventory_incoming_shipment_count, inventory_outgoing_shipment_count,
+                inventory_average_restock_days, inventory_discontinued_product_count,
+                inventory_supplier_contact_count, inventory_cycle_count_discrepancies,
+            },
+            ShippingQueries {
+                shipping_carrier_account_count, shipping_label_printed_count,
+                shipping_delivered_parcel_count, shipping_returned_parcel_count,
+                shipping_average_transit_days, shipping_customs_declaration_count,
+                shipping_lost_parcel_claim_count, shipping_pickup_location_count,
+                shipping_insured_parcel_count, shipping_international_parcel_count,
+                shipping_rate_quote_request_count, shipping_delayed_parcel_count,
+            },
+            SecurityQueries {
+                security_failed_login_attempt_count, security_active_session_count,
+                security_revoked_token_count, security_api_key_rotation_days,
+                security_audit_log_entry_count, security_blocked_address_count,
+                security_password_reset_request_count, security_trusted_device_count,
+                security_single_sign_on_provider_count, security_open_incident_count,
+                security_role_assignment_count, security_permission_grant_count,
+            },
+        }
+    };
+}
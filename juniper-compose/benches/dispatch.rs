@@ -0,0 +1,221 @@
+//! Latency of resolving queries against composite objects of 1 and 8 composed objects of twelve
+//! fields each, executed through a `RootNode` so that the numbers include juniper's own work.
+//!
+//! Run with `cargo bench -p juniper_compose_ng --bench dispatch`. Each case runs for about a
+//! second against the composite and against a plain `#[graphql_object]` of the same fields, and
+//! prints the mean time per query of both. Comparing with the plain object rather than with
+//! recorded times keeps the guard independent of the machine: the benchmark exits with an error
+//! if a composite is more than [`MAX_OVERHEAD`] times slower than its plain object.
+
+// The generated code refers to the runtime crate by the name it is depended on by.
+extern crate juniper_compose_ng as juniper_compose;
+
+use juniper::{EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object};
+use juniper_compose::{composable_object, composite_object};
+use std::{
+    future::Future,
+    hint::black_box,
+    pin::pin,
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+#[macro_use]
+mod common;
+
+/// Declares a composable object of the fields of every `Member { fields }`, each resolving to
+/// zero, and composes the first of them in the composite object `One` and all of them in `Eight`,
+/// along with plain objects of the same fields and the `snake_case` names of their fields.
+macro_rules! composites {
+    (
+        $first:ident { $( $first_field:ident ),* $(,)? }
+        $( , $member:ident { $( $field:ident ),* $(,)? } )* $(,)?
+    ) => {
+        $( composable!($member { $( $field ),* }); )*
+        composable!($first { $( $first_field ),* });
+
+        composite_object!(One($first));
+        composite_object!(Eight($first $( , $member )*));
+
+        plain!(PlainOne { $( $first_field ),* });
+        plain!(PlainEight { $( $first_field, )* $( $( $field, )* )* });
+
+        const ONE_FIELDS: &[&str] = &[$( stringify!($first_field) ),*];
+        const EIGHT_FIELDS: &[&str] =
+            &[$( stringify!($first_field), )* $( $( stringify!($field), )* )*];
+    };
+}
+
+/// Declares a composable object of `fields`, each resolving to zero.
+macro_rules! composable {
+    ($name:ident { $( $field:ident ),* }) => {
+        #[derive(Default)]
+        struct $name;
+
+        #[composable_object]
+        #[graphql_object]
+        impl $name {
+            $(
+                fn $field() -> i32 {
+                    0
+                }
+            )*
+        }
+    };
+}
+
+/// Declares a plain `#[graphql_object]` of `fields`, to compare a composite of the same fields
+/// against.
+macro_rules! plain {
+    ($name:ident { $( $field:ident ),* $(,)? }) => {
+        struct $name;
+
+        #[graphql_object]
+        impl $name {
+            $(
+                fn $field() -> i32 {
+                    0
+                }
+            )*
+        }
+    };
+}
+
+with_members!(composites);
+
+const DURATION: Duration = Duration::from_secs(1);
+
+/// How many times slower than the plain object of the same fields a composite may be before the
+/// benchmark fails. Composites measured between 0.9x and 1.3x from run to run, so the margin is
+/// meant to absorb noise rather than hide a dispatch that grows with the number of members.
+const MAX_OVERHEAD: f64 = 1.5;
+
+/// The resolvers never suspend, so the futures complete without being woken.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+fn bench<Q>(name: &str, query: Q, source: &str) -> Duration
+where
+    Q: juniper::GraphQLTypeAsync<Context = (), TypeInfo = ()> + Send + Sync,
+{
+    let schema = RootNode::new(
+        query,
+        EmptyMutation::<()>::new(),
+        EmptySubscription::<()>::new(),
+    );
+    let variables = Variables::new();
+    let run = || {
+        let (_, errors) = block_on(juniper::execute(
+            black_box(source),
+            None,
+            &schema,
+            &variables,
+            &(),
+        ))
+        .expect("the query is valid");
+        assert!(errors.is_empty(), "{errors:?}");
+    };
+    for _ in 0..100 {
+        run();
+    }
+    let start = Instant::now();
+    let mut iterations = 0u32;
+    while start.elapsed() < DURATION {
+        run();
+        iterations += 1;
+    }
+    let mean = start.elapsed() / iterations;
+    println!("{name:<24} {mean:>10.2?}/query ({iterations} queries)");
+    mean
+}
+
+/// Returns the name juniper exposes the `snake_case` resolver `field` under.
+fn camel_case(field: &str) -> String {
+    let mut words = field.split('_');
+    let mut name = words.next().unwrap_or_default().to_owned();
+    for word in words {
+        let mut chars = word.chars();
+        name.extend(chars.next().map(|first| first.to_ascii_uppercase()));
+        name.push_str(chars.as_str());
+    }
+    name
+}
+
+/// Selects the last of `fields`.
+fn last_field(fields: &[&str]) -> String {
+    format!("{{ {} }}", camel_case(fields[fields.len() - 1]))
+}
+
+/// Selects every one of `fields`.
+fn all_fields(fields: &[&str]) -> String {
+    let fields = fields
+        .iter()
+        .map(|field| camel_case(field))
+        .collect::<Vec<_>>();
+    format!("{{ {} }}", fields.join(" "))
+}
+
+/// Benchmarks `source` against the composite `composite` and against the plain object `plain` of
+/// the same fields, and returns how many times slower the composite is.
+fn compare<C, P>(name: &str, composite: C, plain: P, source: &str) -> f64
+where
+    C: juniper::GraphQLTypeAsync<Context = (), TypeInfo = ()> + Send + Sync,
+    P: juniper::GraphQLTypeAsync<Context = (), TypeInfo = ()> + Send + Sync,
+{
+    let composite = bench(name, composite, source);
+    let plain = bench(&format!("{name} (plain)"), plain, source);
+    composite.as_secs_f64() / plain.as_secs_f64()
+}
+
+fn main() {
+    let overheads = [
+        (
+            "1 member, last",
+            compare("1 member, last", One, PlainOne, &last_field(ONE_FIELDS)),
+        ),
+        (
+            "1 member, all",
+            compare("1 member, all", One, PlainOne, &all_fields(ONE_FIELDS)),
+        ),
+        (
+            "8 members, last",
+            compare(
+                "8 members, last",
+                Eight,
+                PlainEight,
+                &last_field(EIGHT_FIELDS),
+            ),
+        ),
+        (
+            "8 members, all",
+            compare(
+                "8 members, all",
+                Eight,
+                PlainEight,
+                &all_fields(EIGHT_FIELDS),
+            ),
+        ),
+    ];
+    println!();
+    let mut regressed = false;
+    for (name, overhead) in overheads {
+        let verdict = if overhead > MAX_OVERHEAD {
+            regressed = true;
+            "regressed"
+        } else {
+            "ok"
+        };
+        println!("{name:<24} {overhead:>10.2}x plain {verdict}");
+    }
+    if regressed {
+        eprintln!("dispatch is more than {MAX_OVERHEAD}x slower than a plain object");
+        std::process::exit(1);
+    }
+}
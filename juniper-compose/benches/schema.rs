@@ -0,0 +1,104 @@
+//! Time to build the schema of a composite object of eight composed objects of twelve fields each,
+//! against the same 96 fields declared by a single `#[graphql_object]`, so that the difference is
+//! the work the composite adds to building its meta type.
+//!
+//! Run with `cargo bench -p juniper_compose_ng --bench schema`. Each case runs for about a second
+//! and prints the mean time per `RootNode::new`; compare them before and after changing how
+//! composites build their meta types.
+
+// The generated code refers to the runtime crate by the name it is depended on by.
+extern crate juniper_compose_ng as juniper_compose;
+
+use juniper::{
+    EmptyMutation, EmptySubscription, GraphQLObject, GraphQLTypeAsync, RootNode, graphql_object,
+};
+use juniper_compose::{composable_object, composite_object};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+#[derive(GraphQLObject)]
+struct Item {
+    id: i32,
+    name: String,
+}
+
+#[macro_use]
+mod common;
+
+/// Declares a composable object for every `Member { fields }`, each field taking an argument
+/// with a description and a default value, composes them in the composite object `Query`, and
+/// declares the same fields on the object `Monolithic`.
+macro_rules! schema {
+    ($( $member:ident { $( $field:ident ),* $(,)? } ),* $(,)?) => {
+        $(
+            #[derive(Default)]
+            struct $member;
+
+            #[composable_object]
+            #[graphql_object]
+            impl $member {
+                $(
+                    /// Lists items.
+                    fn $field(
+                        #[graphql(description = "Number of items.", default = 10)] first: i32,
+                    ) -> Vec<Item> {
+                        black_box(first);
+                        Vec::new()
+                    }
+                )*
+            }
+        )*
+
+        composite_object!(Query($( $member ),*));
+
+        struct Monolithic;
+
+        #[graphql_object(name = "Query")]
+        impl Monolithic {
+            $( $(
+                /// Lists items.
+                fn $field(
+                    #[graphql(description = "Number of items.", default = 10)] first: i32,
+                ) -> Vec<Item> {
+                    black_box(first);
+                    Vec::new()
+                }
+            )* )*
+        }
+    };
+}
+
+with_members!(schema);
+
+const DURATION: Duration = Duration::from_secs(1);
+
+fn bench<Q>(name: &str, query: impl Fn() -> Q)
+where
+    Q: GraphQLTypeAsync<Context = (), TypeInfo = ()> + Send + Sync,
+{
+    let run = || {
+        black_box(RootNode::new(
+            query(),
+            EmptyMutation::<()>::new(),
+            EmptySubscription::<()>::new(),
+        ));
+    };
+    for _ in 0..100 {
+        run();
+    }
+    let start = Instant::now();
+    let mut iterations = 0u32;
+    while start.elapsed() < DURATION {
+        run();
+        iterations += 1;
+    }
+    let mean = start.elapsed() / iterations;
+    println!("{name:<24} {mean:>10.2?}/schema ({iterations} schemas)");
+}
+
+fn main() {
+    bench("96 fields, monolithic", || Monolithic);
+    bench("96 fields, composite", || Query);
+}
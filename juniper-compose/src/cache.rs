@@ -0,0 +1,61 @@
+//! Cache of the merged fields of composite types.
+//!
+//! Juniper builds the meta type of a composite once for every schema it is part of. The fields it
+//! merges from its members are the same every time unless it has type info, so composites without
+//! type info only merge them the first time on each thread. The members' meta types are still
+//! built for every registry, which registers the types their fields refer to. Composite input
+//! objects without type info likewise only check the types their members register once.
+
+use juniper::{ScalarValue, meta::Field};
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
+
+thread_local! {
+    /// Merged fields of each composite type, keyed by the type and scalar value type.
+    static FIELDS: RefCell<HashMap<(TypeId, TypeId), Box<dyn Any>>> = RefCell::default();
+
+    /// Composite input object types whose members' types have been checked, keyed like `FIELDS`.
+    static CHECKED_TYPES: RefCell<HashSet<(TypeId, TypeId)>> = RefCell::default();
+}
+
+/// Returns the fields the composite `T` merges for `S`, merging them with `merge` if they haven't
+/// been yet on this thread.
+pub fn merged_fields<T, S>(merge: impl FnOnce() -> Vec<Field<'static, S>>) -> Vec<Field<'static, S>>
+where
+    T: ?Sized + 'static,
+    S: ScalarValue + 'static,
+{
+    let key = (TypeId::of::<T>(), TypeId::of::<S>());
+    let cached = FIELDS.with_borrow(|cache| {
+        cache
+            .get(&key)
+            .and_then(|fields| fields.downcast_ref::<Vec<Field<'static, S>>>())
+            .cloned()
+    });
+    if let Some(fields) = cached {
+        return fields;
+    }
+    // Merging may build the meta type of a nested composite, so the cache must not be borrowed
+    // in the meantime.
+    let fields = merge();
+    FIELDS.with_borrow_mut(|cache| cache.insert(key, Box::new(fields.clone())));
+    fields
+}
+
+/// Calls `check`, which checks the types the members of the composite `T` register for `S`,
+/// unless it has already been called on this thread.
+pub fn check_types_once<T, S>(check: impl FnOnce())
+where
+    T: ?Sized + 'static,
+    S: ScalarValue + 'static,
+{
+    let key = (TypeId::of::<T>(), TypeId::of::<S>());
+    if CHECKED_TYPES.with_borrow(|checked| checked.contains(&key)) {
+        return;
+    }
+    check();
+    CHECKED_TYPES.with_borrow_mut(|checked| checked.insert(key));
+}
@@ -0,0 +1,529 @@
+//! Compile-time checks emitted by the [composite_object](crate::composite_object),
+//! [composite_interface](crate::composite_interface) and [composite_union](crate::composite_union)
+//! macros.
+//!
+//! Everything here is a `const fn` so that a failed check aborts constant evaluation (or fails to
+//! type check) and shows up as a compile error at the macro invocation.
+
+use crate::{
+    ComposableInputObject, ComposableInterface, ComposableObject, ComposableSubscription,
+    ComposableUnion, FieldType,
+    member::{ExposedName, Member},
+};
+use juniper::{FromContext, GraphQLValue, ScalarValue};
+
+const MESSAGE_CAPACITY: usize = 1024;
+
+/// Fails constant evaluation if two of `members`, the members of a composite, expose a field
+/// under the same name.
+///
+/// The panic message lists every shared field, naming all the members exposing it. The fallback
+/// member of a composite object is exempt, as the other members take precedence over it.
+pub const fn assert_disjoint(members: &[Member]) {
+    assert_names_disjoint(members, "field `", "defined by ");
+}
+
+/// Fails constant evaluation if two of `members`, the members of a composite union, have the same
+/// variant type.
+///
+/// Reported like [`assert_disjoint`], with the variant types of the members as their fields.
+pub const fn assert_variants_disjoint(members: &[Member]) {
+    assert_names_disjoint(members, "type `", "a variant of ");
+}
+
+/// Fails constant evaluation if two of `members` that aren't fallbacks share a name. Each shared
+/// name is reported as ``{noun}{name}` is {shared}``, followed by the members sharing it.
+///
+/// The names are checked in a single pass: each name of a member is compared with the names of the
+/// other members, and a shared name is reported once, by the first member exposing it.
+const fn assert_names_disjoint(members: &[Member], noun: &str, shared: &str) {
+    let mut message = Message::new();
+    let mut m = 0;
+    while m < members.len() {
+        let (earlier, rest) = members.split_at(m);
+        let (member, later) = rest.split_at(1);
+        let member = &member[0];
+        m += 1;
+        if member.fallback {
+            continue;
+        }
+        let mut i = 0;
+        let mut j = 0;
+        while i < member.fields.len() {
+            if j == member.name_count(member.fields[i]) {
+                i += 1;
+                j = 0;
+                continue;
+            }
+            let name = member.name(member.fields[i], j);
+            j += 1;
+            let definers = 1 + count_definers(later, &name);
+            if definers == 1 || count_definers(earlier, &name) > 0 {
+                continue;
+            }
+            if !message.is_empty() {
+                message.push("; ");
+            }
+            message.push(noun);
+            message.push_name(&name);
+            message.push("` is ");
+            message.push(shared);
+            if definers == 2 {
+                message.push("both ");
+            }
+            message.push("`");
+            message.push(member.type_name);
+            message.push("`");
+            let mut listed = 1;
+            let mut k = 0;
+            while k < later.len() {
+                if !later[k].fallback && later[k].exposes_name(&name) {
+                    message.push(if listed == definers - 1 {
+                        " and `"
+                    } else {
+                        ", `"
+                    });
+                    message.push(later[k].type_name);
+                    message.push("`");
+                    listed += 1;
+                }
+                k += 1;
+            }
+        }
+    }
+    assert!(message.is_empty(), "{}", message.as_str());
+}
+
+/// Returns the number of `members` that aren't fallbacks and expose `name`.
+const fn count_definers(members: &[Member], name: &ExposedName) -> usize {
+    let mut definers = 0;
+    let mut k = 0;
+    while k < members.len() {
+        if !members[k].fallback && members[k].exposes_name(name) {
+            definers += 1;
+        }
+        k += 1;
+    }
+    definers
+}
+
+/// Fails constant evaluation if `member` renames a field it doesn't have.
+pub const fn assert_renamed_fields_exist(member: &Member) {
+    let mut message = Message::new();
+    let mut i = 0;
+    while i < member.renames.len() {
+        let field = member.renames[i].0;
+        let mut found = false;
+        let mut j = 0;
+        while j < member.fields.len() {
+            if str_eq(field, member.fields[j]) {
+                found = true;
+            }
+            j += 1;
+        }
+        if !found {
+            if !message.is_empty() {
+                message.push("; ");
+            }
+            message.push("cannot rename field `");
+            message.push(field);
+            message.push("`: it is not defined by `");
+            message.push(member.type_name);
+            message.push("`");
+        }
+        i += 1;
+    }
+    assert!(message.is_empty(), "{}", message.as_str());
+}
+
+/// Fails constant evaluation if `member` excludes a field it doesn't have.
+pub const fn assert_excluded_fields_exist(member: &Member) {
+    assert_listed_fields_exist(member, member.excluded, "exclude");
+}
+
+/// Fails constant evaluation if `member` is limited to a field it doesn't have.
+pub const fn assert_selected_fields_exist(member: &Member) {
+    if let Some(only) = member.only {
+        assert_listed_fields_exist(member, only, "select");
+    }
+}
+
+/// Fails constant evaluation if `member` doesn't have one of `fields`, listed to `action` them.
+const fn assert_listed_fields_exist(member: &Member, fields: &[&str], action: &str) {
+    let mut message = Message::new();
+    let mut i = 0;
+    while i < fields.len() {
+        let field = fields[i];
+        let mut found = false;
+        let mut j = 0;
+        while j < member.fields.len() {
+            if str_eq(field, member.fields[j]) {
+                found = true;
+            }
+            j += 1;
+        }
+        if !found {
+            if !message.is_empty() {
+                message.push("; ");
+            }
+            message.push("cannot ");
+            message.push(action);
+            message.push(" field `");
+            message.push(field);
+            message.push("`: it is not defined by `");
+            message.push(member.type_name);
+            message.push("`");
+        }
+        i += 1;
+    }
+    assert!(message.is_empty(), "{}", message.as_str());
+}
+
+/// Fails constant evaluation if the implementer of an interface doesn't define every field of
+/// `member`, one of the composables the interface is composed of.
+pub const fn assert_implemented(member: &Member, implementer_name: &str, implementer: &[&str]) {
+    let mut message = Message::new();
+    let mut i = 0;
+    while i < member.fields.len() {
+        let field = member.fields[i];
+        let mut found = false;
+        let mut j = 0;
+        while j < implementer.len() {
+            if str_eq(field, implementer[j]) {
+                found = true;
+            }
+            j += 1;
+        }
+        if !found {
+            if !message.is_empty() {
+                message.push("; ");
+            }
+            message.push("field `");
+            message.push(field);
+            message.push("` of `");
+            message.push(member.type_name);
+            message.push("` is not defined by implementer `");
+            message.push(implementer_name);
+            message.push("`");
+        }
+        i += 1;
+    }
+    assert!(message.is_empty(), "{}", message.as_str());
+}
+
+/// Fails constant evaluation if the composite object `composite_name`, exposing `fields`, doesn't
+/// define every field of the interface `interface_name` it implements, or defines one with a type
+/// the field of the interface can't be implemented by. `field_types` and `interface_field_types`
+/// are the types of `fields` and `interface_fields`, and the types are only checked if both are
+/// known.
+pub const fn assert_interface_fields(
+    composite_name: &str,
+    fields: &[&str],
+    field_types: &[FieldType],
+    interface_name: &str,
+    interface_fields: &[&str],
+    interface_field_types: &[FieldType],
+) {
+    let check_types =
+        field_types.len() == fields.len() && interface_field_types.len() == interface_fields.len();
+    let mut message = Message::new();
+    let mut i = 0;
+    while i < interface_fields.len() {
+        let field = interface_fields[i];
+        let mut found = None;
+        let mut j = 0;
+        while j < fields.len() {
+            if str_eq(field, fields[j]) {
+                found = Some(j);
+            }
+            j += 1;
+        }
+        match found {
+            None => {
+                if !message.is_empty() {
+                    message.push("; ");
+                }
+                message.push("field `");
+                message.push(field);
+                message.push("` of interface `");
+                message.push(interface_name);
+                message.push("` is not defined by `");
+                message.push(composite_name);
+                message.push("`");
+            }
+            Some(j) if check_types && !interface_field_types[i].accepts(&field_types[j]) => {
+                if !message.is_empty() {
+                    message.push("; ");
+                }
+                message.push("field `");
+                message.push(field);
+                message.push("` of interface `");
+                message.push(interface_name);
+                message.push("` has type `");
+                message.push_type(&interface_field_types[i]);
+                message.push("`, but `");
+                message.push(composite_name);
+                message.push("` defines it with type `");
+                message.push_type(&field_types[j]);
+                message.push("`");
+            }
+            Some(_) => {}
+        }
+        i += 1;
+    }
+    assert!(message.is_empty(), "{}", message.as_str());
+}
+
+/// Fails constant evaluation if `fields`, the fields of the composite `composite_name`, are empty,
+/// which happens when its members have no fields, or have all of them excluded. Composites with
+/// `dynamic_fields` may only know their fields at run time, so they pass.
+pub const fn assert_has_fields(composite_name: &str, fields: &[&str], dynamic_fields: bool) {
+    let mut message = Message::new();
+    if fields.is_empty() && !dynamic_fields {
+        message.push("composite `");
+        message.push(composite_name);
+        message.push(
+            "` has no fields, since its members expose none, \
+             but a GraphQL type has at least one field",
+        );
+    }
+    assert!(message.is_empty(), "{}", message.as_str());
+}
+
+/// Fails constant evaluation if `member`, a member of the `sync` composite object
+/// `composite_name` listing only its asynchronously resolved fields, has any such field.
+pub const fn assert_sync(composite_name: &str, member: &Member) {
+    let mut message = Message::new();
+    let mut i = 0;
+    while i < member.fields.len() {
+        if !member.exposes(member.fields[i]) {
+            i += 1;
+            continue;
+        }
+        if !message.is_empty() {
+            message.push("; ");
+        }
+        message.push("field `");
+        message.push_name(&member.exposed_name(member.fields[i]));
+        message.push("` of `");
+        message.push(member.type_name);
+        message.push("` is async, so it can't be resolved by `sync` composite object `");
+        message.push(composite_name);
+        message.push("`");
+        i += 1;
+    }
+    assert!(message.is_empty(), "{}", message.as_str());
+}
+
+/// Fails constant evaluation if `member`, a member of the composite object `composite_name`
+/// declared `rename_all = "none"` listing only its [converted
+/// fields](crate::ComposableObject::CONVERTED_FIELDS), exposes any such field under the name its
+/// policy gave it rather than one the composite gives it.
+pub const fn assert_unconverted(composite_name: &str, member: &Member) {
+    let mut message = Message::new();
+    let mut i = 0;
+    while i < member.fields.len() {
+        let field = member.fields[i];
+        i += 1;
+        if !member.exposes(field) || member.is_renamed(field) {
+            continue;
+        }
+        if !message.is_empty() {
+            message.push("; ");
+        }
+        message.push("field `");
+        message.push(field);
+        message.push("` of `");
+        message.push(member.type_name);
+        message.push("` is named by its `rename_all` policy, but composite object `");
+        message.push(composite_name);
+        message.push(
+            "` is declared `rename_all = \"none\"`: declare the member \
+             `rename_all = \"none\"` as well, or name the field explicitly",
+        );
+    }
+    assert!(message.is_empty(), "{}", message.as_str());
+}
+
+/// Fails to type check unless `T`, a member of a composite object, is a composable object with
+/// context `C` and type info `I`. The error names `T` at the point it is listed.
+///
+/// The checks of a member are a single bound, so that a member failing them gets a single error
+/// naming it, reported ahead of the errors of the generated code: a type that isn't a composable
+/// object is reported as such, rather than also for its context and type info.
+pub const fn assert_composable_object<T, C, I, S>()
+where
+    T: ComposableObject<S> + GraphQLValue<S, Context = C, TypeInfo = I> + ?Sized,
+    C: Sync + ?Sized,
+    I: Sync + ?Sized,
+    S: ScalarValue + Send + Sync,
+{
+}
+
+/// Fails to type check unless `T`, a member of a composite subscription, is a `Send` composable
+/// subscription with context `C` and type info `I`. The member is moved into the future resolving
+/// its stream, which is sent between threads along with it.
+pub const fn assert_composable_subscription<T, C, I, S>()
+where
+    T: ComposableSubscription<S> + GraphQLValue<S, Context = C, TypeInfo = I> + Send + ?Sized,
+    C: Sync + ?Sized,
+    I: Sync + ?Sized,
+    S: ScalarValue + Send + Sync,
+{
+}
+
+/// Fails to type check unless `T`, a member of a composite interface, is a composable interface
+/// with context `C` and type info `I`.
+pub const fn assert_composable_interface<T, C, I, S>()
+where
+    T: ComposableInterface<S> + GraphQLValue<S, Context = C, TypeInfo = I> + ?Sized,
+    C: ?Sized,
+    I: ?Sized,
+    S: ScalarValue,
+{
+}
+
+/// Fails to type check unless `T`, a member of a composite input object, is a composable input
+/// object with context `C` and type info `I`.
+pub const fn assert_composable_input_object<T, C, I, S>()
+where
+    T: ComposableInputObject<S> + GraphQLValue<S, Context = C, TypeInfo = I>,
+    C: ?Sized,
+    I: ?Sized,
+    S: ScalarValue,
+{
+}
+
+/// Fails to type check unless `T`, a member of a composite union, is a composable union with
+/// context `C` and type info `I`.
+pub const fn assert_composable_union<T, C, I, S>()
+where
+    T: ComposableUnion<S> + GraphQLValue<S, Context = C, TypeInfo = I> + ?Sized,
+    C: Sync + ?Sized,
+    I: Sync + ?Sized,
+    S: ScalarValue + Send + Sync,
+{
+}
+
+/// Fails to type check unless `C`, the context of a composite type, is `Sync`. Executors sharing
+/// the context resolve fields asynchronously, which juniper's object macros and the composable
+/// traits require of it even for synchronous execution. The context is made `Sync` by wrapping
+/// the state it mutates in a `Mutex`, a `RwLock` or atomics.
+pub const fn assert_context_sync<C>()
+where
+    C: Sync + ?Sized,
+{
+}
+
+/// Fails to type check unless the context `C` of a member can be converted from `A`, the context
+/// of its composite type.
+pub const fn assert_context_from<C, A>()
+where
+    C: FromContext<A> + ?Sized,
+{
+}
+
+pub(crate) const fn str_eq(left: &str, right: &str) -> bool {
+    let (left, right) = (left.as_bytes(), right.as_bytes());
+    if left.len() != right.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < left.len() {
+        if left[i] != right[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Fixed-capacity string buffer usable in constant evaluation.
+///
+/// Fragments that don't fit are replaced with a trailing `...` so the buffer always holds
+/// valid UTF-8.
+struct Message {
+    bytes: [u8; MESSAGE_CAPACITY],
+    len: usize,
+    truncated: bool,
+}
+
+impl Message {
+    const fn new() -> Self {
+        Self {
+            bytes: [0; MESSAGE_CAPACITY],
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    const fn push(&mut self, fragment: &str) {
+        if self.truncated {
+            return;
+        }
+        let fragment = fragment.as_bytes();
+        if self.len + fragment.len() > MESSAGE_CAPACITY - 3 {
+            self.truncated = true;
+            let mut i = 0;
+            while i < 3 {
+                self.bytes[self.len] = b'.';
+                self.len += 1;
+                i += 1;
+            }
+            return;
+        }
+        let mut i = 0;
+        while i < fragment.len() {
+            self.bytes[self.len] = fragment[i];
+            self.len += 1;
+            i += 1;
+        }
+    }
+
+    const fn push_name(&mut self, name: &ExposedName) {
+        let mut bytes = [0; MESSAGE_CAPACITY];
+        let mut i = 0;
+        let mut name = name.bytes();
+        while i < MESSAGE_CAPACITY
+            && let Some(byte) = name.next()
+        {
+            bytes[i] = byte;
+            i += 1;
+        }
+        match core::str::from_utf8(bytes.split_at(i).0) {
+            Ok(name) => self.push(name),
+            Err(_) => self.push("<invalid name>"),
+        }
+    }
+
+    /// Pushes `ty` the way GraphQL writes it, e.g. `[ID!]`.
+    const fn push_type(&mut self, ty: &FieldType) {
+        self.push_wrapped(ty.name, ty.wrapped);
+    }
+
+    const fn push_wrapped(&mut self, name: &str, wrapped: u128) {
+        let nullable = wrapped % 10 == 2;
+        let wrapped = if nullable { wrapped / 10 } else { wrapped };
+        if wrapped % 10 == 3 {
+            self.push("[");
+            self.push_wrapped(name, wrapped / 10);
+            self.push("]");
+        } else {
+            self.push(name);
+        }
+        if !nullable {
+            self.push("!");
+        }
+    }
+
+    const fn as_str(&self) -> &str {
+        match core::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(message) => message,
+            Err(_) => "<invalid message>",
+        }
+    }
+}
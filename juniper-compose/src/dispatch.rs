@@ -0,0 +1,402 @@
+//! Resolution of the fields of composite types by their members.
+//!
+//! The generated dispatch of a composite only finds the member resolving a field and constructs
+//! it. The future resolving the field with it comes from these functions, which are instantiated
+//! once for every member type rather than once for every member of every composite.
+//!
+//! [`member_fields`], [`dispatch_field`] and [`dispatch_field_async`] are the building blocks of
+//! composites written by hand; see the crate documentation.
+
+use crate::{ComposableObject, type_to_owned};
+use juniper::{
+    Arguments, BoxFuture, ExecutionResult, Executor, FieldError, FromContext,
+    GraphQLSubscriptionValue, GraphQLType, GraphQLValue, GraphQLValueAsync, Registry, ScalarValue,
+    Value, ValuesStream,
+    meta::{Argument, DeprecationStatus, Field, InterfaceMeta, MetaType, ObjectMeta},
+};
+use std::{cell::Cell, collections::HashMap};
+
+thread_local! {
+    /// Whether the types of the members of a composite are being registered on this thread, in
+    /// which case the composites they refer to don't check theirs.
+    static REGISTERING_TYPES: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Returns the fields of the composable object `T`, in the order of its
+/// [fields](ComposableObject::fields), to build the meta type of a composite written by hand.
+/// Builds the meta type of `T`, registering the types its fields refer to in `registry`.
+///
+/// # Panics
+///
+/// Panics if `T` lists a field in its fields that its meta type doesn't have.
+pub fn member_fields<'r, T, S>(
+    info: &T::TypeInfo,
+    registry: &mut Registry<'r, S>,
+) -> Vec<Field<'r, S>>
+where
+    T: ComposableObject<S>,
+    T::TypeInfo: Sync,
+    T::Context: Sync,
+    S: ScalarValue + Send + Sync + 'r,
+{
+    let meta = T::meta(info, registry);
+    T::fields()
+        .iter()
+        .map(|&name| {
+            let field = meta.field_by_name(name).unwrap_or_else(|| {
+                panic!(
+                    "Incorrect implementation of ComposableObject on type {}: unknown field {}",
+                    T::TYPE_NAME,
+                    name,
+                )
+            });
+            exposed_field(field, &field.name, field.deprecation_status.clone())
+        })
+        .collect()
+}
+
+/// Copies `field`, a field of the meta type of a member, into a field of the meta type of a
+/// composite exposed under `name`, with `deprecation_status`.
+#[must_use]
+pub fn exposed_field<'r, S: Clone>(
+    field: &Field<'_, S>,
+    name: &str,
+    deprecation_status: DeprecationStatus,
+) -> Field<'r, S> {
+    Field {
+        name: name.into(),
+        description: field.description.clone(),
+        arguments: field.arguments.as_ref().map(|arguments| {
+            arguments
+                .iter()
+                .map(|argument| Argument {
+                    name: argument.name.clone(),
+                    description: argument.description.clone(),
+                    arg_type: type_to_owned(&argument.arg_type),
+                    default_value: argument.default_value.clone(),
+                })
+                .collect()
+        }),
+        field_type: type_to_owned(&field.field_type),
+        deprecation_status,
+    }
+}
+
+/// Builds the meta type of the composite object `T` from its merged `fields`, like
+/// [`Registry::build_object_type`], which copies them twice.
+///
+/// # Panics
+///
+/// Panics if `T` has no name.
+pub fn object_meta<'r, T, S>(
+    info: &T::TypeInfo,
+    registry: &mut Registry<'r, S>,
+    mut fields: Vec<Field<'r, S>>,
+) -> ObjectMeta<'r, S>
+where
+    T: GraphQLType<S> + ?Sized,
+    S: ScalarValue + 'r,
+{
+    let name = T::name(info).expect("Object types must be named. Implement name()");
+    fields.push(registry.field::<String>("__typename", &()));
+    ObjectMeta {
+        name: name.to_owned().into(),
+        description: None,
+        fields,
+        interface_names: Vec::new(),
+    }
+}
+
+/// Builds the meta type of the composite interface `T` from its merged `fields`, like
+/// [`Registry::build_interface_type`], which copies them twice.
+///
+/// # Panics
+///
+/// Panics if `T` has no name.
+pub fn interface_meta<'r, T, S>(
+    info: &T::TypeInfo,
+    registry: &mut Registry<'r, S>,
+    mut fields: Vec<Field<'r, S>>,
+) -> InterfaceMeta<'r, S>
+where
+    T: GraphQLType<S> + ?Sized,
+    S: ScalarValue + 'r,
+{
+    let name = T::name(info).expect("Interface types must be named. Implement name()");
+    fields.push(registry.field::<String>("__typename", &()));
+    InterfaceMeta {
+        name: name.to_owned().into(),
+        description: None,
+        fields,
+        interface_names: Vec::new(),
+    }
+}
+
+/// Types the members of a composite refer to, to detect two members registering different types
+/// under the same name. Juniper registers the first type of a name it comes across and ignores
+/// the others, so the fields of the second member would silently refer to the type of the first.
+///
+/// Every member registers its types in a registry of its own, whose types are compared by shape:
+/// their kind, fields, arguments, values, members and interfaces, but not their descriptions.
+/// Scalars of the same name are always the same shape.
+pub struct TypeRegistrations {
+    composite: &'static str,
+    /// Shape of every type registered so far, with the name of the member registering it first.
+    types: HashMap<String, (String, String)>,
+}
+
+impl TypeRegistrations {
+    /// Starts registering the types of the members of the composite named `composite`.
+    #[must_use]
+    pub fn new(composite: &'static str) -> Self {
+        Self {
+            composite,
+            types: HashMap::new(),
+        }
+    }
+
+    /// Registers the types the member `T` refers to, unless the types of the members of another
+    /// composite are being registered, which `T` is then nested in.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a member registered before refers to a type of the same name with another
+    /// shape, naming the type, both members and both shapes.
+    pub fn register<T, S>(&mut self, info: &T::TypeInfo)
+    where
+        T: GraphQLType<S> + ?Sized,
+        S: ScalarValue,
+    {
+        if REGISTERING_TYPES.get() {
+            return;
+        }
+        let member = T::name(info).unwrap_or("<anonymous>").to_owned();
+        let mut registry = Registry::new(HashMap::default());
+        REGISTERING_TYPES.set(true);
+        // Reset even if building the meta type panics, e.g. on a conflict of a nested composite.
+        let registering = Registering;
+        T::meta(info, &mut registry);
+        drop(registering);
+        let mut types = registry
+            .types
+            .values()
+            .filter_map(|meta| Some((meta.name()?.to_owned(), shape(meta))))
+            .collect::<Vec<_>>();
+        types.sort();
+        for (name, shape) in types {
+            match self.types.get(&name) {
+                Some((first, first_shape)) if *first_shape != shape => panic!(
+                    "Conflicting type in composed objects of {}: {} registers `{}` as `{}`, but {} \
+                     registers it as `{}`",
+                    self.composite, first, name, first_shape, member, shape,
+                ),
+                Some(_) => {}
+                None => {
+                    self.types.insert(name, (member.clone(), shape));
+                }
+            }
+        }
+    }
+}
+
+/// Clears [`REGISTERING_TYPES`] when dropped.
+struct Registering;
+
+impl Drop for Registering {
+    fn drop(&mut self) {
+        REGISTERING_TYPES.set(false);
+    }
+}
+
+/// Describes the shape of the meta type `meta`, in the syntax of a schema definition without the
+/// name of the type.
+fn shape<S>(meta: &MetaType<'_, S>) -> String {
+    let fields = |fields: &[Field<'_, S>]| {
+        fields
+            .iter()
+            .filter(|field| !field.name.starts_with("__"))
+            .map(|field| {
+                let arguments = field
+                    .arguments
+                    .as_deref()
+                    .map_or_else(String::new, |arguments| {
+                        format!("({})", arguments_shape(arguments))
+                    });
+                format!("{}{}: {}", field.name, arguments, field.field_type)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let implements = |interface_names: &[String]| {
+        if interface_names.is_empty() {
+            String::new()
+        } else {
+            format!("implements {} ", interface_names.join(" & "))
+        }
+    };
+    match meta {
+        MetaType::Object(meta) => format!(
+            "type {}{{ {} }}",
+            implements(&meta.interface_names),
+            fields(&meta.fields),
+        ),
+        MetaType::Interface(meta) => format!(
+            "interface {}{{ {} }}",
+            implements(&meta.interface_names),
+            fields(&meta.fields),
+        ),
+        MetaType::InputObject(meta) => {
+            format!("input {{ {} }}", arguments_shape(&meta.input_fields))
+        }
+        MetaType::Enum(meta) => {
+            let values = meta.values.iter().map(|value| value.name.as_str());
+            format!("enum {{ {} }}", values.collect::<Vec<_>>().join(", "))
+        }
+        MetaType::Union(meta) => format!("union = {}", meta.of_type_names.join(" | ")),
+        _ => "scalar".to_owned(),
+    }
+}
+
+/// Describes the shape of `arguments`, or of the fields of an input object.
+fn arguments_shape<S>(arguments: &[Argument<'_, S>]) -> String {
+    arguments
+        .iter()
+        .map(|argument| format!("{}: {}", argument.name, argument.arg_type))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Resolves `field_name` with the member `composable` of a composite written by hand, or returns
+/// `None` if it isn't one of its [fields](ComposableObject::fields).
+pub fn dispatch_field<T, S>(
+    composable: &T,
+    info: &T::TypeInfo,
+    field_name: &str,
+    arguments: &Arguments<'_, S>,
+    executor: &Executor<'_, '_, T::Context, S>,
+) -> Option<ExecutionResult<S>>
+where
+    T: ComposableObject<S>,
+    T::TypeInfo: Sync,
+    T::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    T::fields()
+        .contains(&field_name)
+        .then(|| GraphQLValue::resolve_field(composable, info, field_name, arguments, executor))
+}
+
+/// Resolves `field_name` asynchronously with the member `composable` of a composite written by
+/// hand, or returns `None` if it isn't one of its [fields](ComposableObject::fields).
+pub fn dispatch_field_async<'a, T, S>(
+    composable: &'a T,
+    info: &'a T::TypeInfo,
+    field_name: &'a str,
+    arguments: &'a Arguments<'_, S>,
+    executor: &'a Executor<'_, '_, T::Context, S>,
+) -> Option<BoxFuture<'a, ExecutionResult<S>>>
+where
+    T: ComposableObject<S> + Sync,
+    T::TypeInfo: Sync,
+    T::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    T::fields().contains(&field_name).then(|| {
+        GraphQLValueAsync::resolve_field_async(composable, info, field_name, arguments, executor)
+    })
+}
+
+/// Resolves `field_name` with `composable` asynchronously.
+pub fn resolve_field_async<'a, T, S>(
+    composable: T,
+    info: &'a T::TypeInfo,
+    field_name: &'a str,
+    arguments: &'a Arguments<'_, S>,
+    executor: &'a Executor<'_, '_, T::Context, S>,
+) -> BoxFuture<'a, ExecutionResult<S>>
+where
+    T: GraphQLValueAsync<S> + Send + 'a,
+    T::TypeInfo: Sync,
+    T::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    Box::pin(async move {
+        composable
+            .resolve_field_async(info, field_name, arguments, executor)
+            .await
+    })
+}
+
+/// Resolves `field_name` with `composable` asynchronously, with an executor of its context
+/// converted from `executor`'s.
+pub fn resolve_field_async_as<'a, T, C, S>(
+    composable: T,
+    info: &'a T::TypeInfo,
+    field_name: &'a str,
+    arguments: &'a Arguments<'_, S>,
+    executor: &'a Executor<'_, '_, C, S>,
+) -> BoxFuture<'a, ExecutionResult<S>>
+where
+    T: GraphQLValueAsync<S> + Send + 'a,
+    T::TypeInfo: Sync,
+    T::Context: FromContext<C> + Sync,
+    C: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    Box::pin(async move {
+        let context = <T::Context as FromContext<C>>::from(executor.context());
+        let executor = executor.replaced_context(context);
+        composable
+            .resolve_field_async(info, field_name, arguments, &executor)
+            .await
+    })
+}
+
+/// Maps the error `future` resolves a field to with `map_err`, the `map_err` argument of a
+/// composite object. Values are left untouched.
+pub fn map_err<'a, S>(
+    future: BoxFuture<'a, ExecutionResult<S>>,
+    map_err: fn(FieldError<S>) -> FieldError<S>,
+) -> BoxFuture<'a, ExecutionResult<S>>
+where
+    S: Send + 'a,
+{
+    Box::pin(async move { future.await.map_err(map_err) })
+}
+
+/// Resolves the subscription field `field_name` with `composable` into a stream.
+#[allow(clippy::type_complexity)]
+pub fn resolve_field_into_stream<'i, 'fi, 'args, 'e, 'ref_e, 'res, 'f, T, S>(
+    composable: T,
+    info: &'i T::TypeInfo,
+    field_name: &'fi str,
+    arguments: Arguments<'args, S>,
+    executor: &'ref_e Executor<'ref_e, 'e, T::Context, S>,
+) -> BoxFuture<'f, Result<Value<ValuesStream<'res, S>>, FieldError<S>>>
+where
+    T: GraphQLSubscriptionValue<S> + Send + 'f,
+    T::TypeInfo: Sync,
+    T::Context: Sync,
+    S: ScalarValue + Send + Sync,
+    'fi: 'f,
+    'args: 'f,
+    'ref_e: 'f,
+    'res: 'f,
+    'i: 'res,
+    'e: 'res,
+{
+    Box::pin(async move {
+        composable
+            .resolve_field_into_stream(info, field_name, arguments, executor)
+            .await
+    })
+}
+
+/// Returns the error composites resolve a field they don't expose to, `type_name` being the name
+/// of the composite.
+#[must_use]
+pub fn unknown_field<S>(type_name: &str, field_name: &str) -> FieldError<S> {
+    FieldError::from(format!(
+        "Field `{field_name}` not found on type `{type_name}`"
+    ))
+}
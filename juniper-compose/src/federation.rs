@@ -0,0 +1,288 @@
+//! Fields Apollo Federation requires of the query type of a subgraph, and the resolution of the
+//! entities it references.
+
+use juniper::{
+    Arguments, BoxFuture, DefaultScalarValue, ExecutionResult, Executor, FieldError, GraphQLType,
+    GraphQLValue, GraphQLValueAsync, InputValue, Registry, ScalarValue, Value, meta::MetaType,
+};
+use std::marker::PhantomData;
+
+use crate::{ComposableObject, FieldType};
+
+/// Composable object adding the `_service` field of Apollo Federation to a composite query type,
+/// composed by `composite_object!(Query federation (...))`.
+///
+/// `_service { sdl }` returns the SDL of the schema the query is executed against, without the
+/// `_service` field itself. `C` and `I` are the context and type info of the composite object.
+pub struct Federation<C = (), I = ()>(PhantomData<fn() -> (C, I)>);
+
+impl<C, I> Default for Federation<C, I> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C, I, S> GraphQLType<S> for Federation<C, I>
+where
+    S: ScalarValue,
+{
+    fn name(_: &Self::TypeInfo) -> Option<&str> {
+        Some(FEDERATION_TYPE)
+    }
+
+    fn meta<'r>(info: &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        let fields = [registry.field::<Service<C>>(SERVICE_FIELD, &())];
+        registry
+            .build_object_type::<Self>(info, &fields)
+            .into_meta()
+    }
+}
+
+impl<C, I, S> GraphQLValue<S> for Federation<C, I>
+where
+    S: ScalarValue,
+{
+    type Context = C;
+    type TypeInfo = I;
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+
+    fn resolve_field(
+        &self,
+        _: &Self::TypeInfo,
+        field_name: &str,
+        _: &Arguments<'_, S>,
+        executor: &Executor<'_, '_, Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        if field_name == SERVICE_FIELD {
+            executor.resolve(&(), &Service::<C>(PhantomData))
+        } else {
+            Err(unknown_field(field_name, FEDERATION_TYPE))
+        }
+    }
+}
+
+impl<C, I, S> GraphQLValueAsync<S> for Federation<C, I>
+where
+    C: Sync,
+    I: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_field_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        field_name: &'a str,
+        arguments: &'a Arguments<'_, S>,
+        executor: &'a Executor<'_, '_, Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        let result = self.resolve_field(info, field_name, arguments, executor);
+        Box::pin(async move { result })
+    }
+}
+
+impl<C, I, S> ComposableObject<S> for Federation<C, I>
+where
+    C: Sync,
+    I: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    const TYPE_NAME: &'static str = FEDERATION_TYPE;
+    const FIELDS: &'static [&'static str] = &[SERVICE_FIELD];
+    const FIELD_TYPES: &'static [FieldType] = &[FieldType {
+        name: SERVICE_TYPE,
+        sub_types: &[SERVICE_TYPE],
+        wrapped: 1,
+    }];
+}
+
+/// Representation of an entity, as the router passes it to `_entities`: an object with the
+/// `__typename` of the entity and the fields of one of its keys.
+///
+/// ```
+/// use juniper::{DefaultScalarValue, InputValue};
+/// use juniper_compose_ng::Representation;
+///
+/// let value = InputValue::<DefaultScalarValue>::object(
+///     [("__typename", InputValue::scalar("User")), ("id", InputValue::scalar("1"))]
+///         .into_iter()
+///         .collect(),
+/// );
+/// let representation = Representation::try_from(value).unwrap();
+/// assert_eq!(representation.typename(), "User");
+/// assert_eq!(representation.get("id").and_then(InputValue::as_string_value), Some("1"));
+/// assert_eq!(representation.get("email"), None);
+///
+/// assert!(Representation::try_from(InputValue::<DefaultScalarValue>::scalar("User")).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Representation<S = DefaultScalarValue> {
+    typename: String,
+    fields: Vec<(String, InputValue<S>)>,
+}
+
+impl<S> Representation<S> {
+    /// Returns the name of the type of the entity.
+    #[must_use]
+    pub fn typename(&self) -> &str {
+        &self.typename
+    }
+
+    /// Returns the value of the key field `name`, or `None` if the representation doesn't have
+    /// it.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&InputValue<S>> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl<S: ScalarValue> TryFrom<InputValue<S>> for Representation<S> {
+    type Error = String;
+
+    /// Converts an object with a `__typename` string into a representation, failing with the
+    /// error message `_entities` is expected to return otherwise.
+    fn try_from(value: InputValue<S>) -> Result<Self, Self::Error> {
+        let InputValue::Object(fields) = value else {
+            return Err("Representations must be objects".into());
+        };
+        let mut typename = None;
+        let mut key_fields = Vec::with_capacity(fields.len());
+        for (name, value) in fields {
+            if name.item == TYPENAME_FIELD {
+                typename = value.item.as_string_value().map(str::to_owned);
+            } else {
+                key_fields.push((name.item, value.item));
+            }
+        }
+        let typename = typename
+            .ok_or_else(|| format!("Representations must have a `{TYPENAME_FIELD}` string"))?;
+        Ok(Self {
+            typename,
+            fields: key_fields,
+        })
+    }
+}
+
+/// Composable objects that resolve the entities a subgraph references, the
+/// `__resolveReference` resolvers of Apollo Federation. Listed as
+/// `resolve_reference MyQueries` in a [`composite_object`](crate::composite_object) declared
+/// `federation`, which implements [`ResolveEntities`] by trying each such member in turn.
+pub trait ResolveReference<S = DefaultScalarValue>: GraphQLValue<S>
+where
+    S: ScalarValue,
+{
+    /// Type of the entities, usually the `_Entity` union of the subgraph, which every member
+    /// resolving references of a composite returns.
+    type Entity;
+
+    /// Resolves the entity `representation` refers to, or returns `None` if its type belongs to
+    /// another member or no entity has its key.
+    fn resolve_reference(
+        context: &Self::Context,
+        representation: &Representation<S>,
+    ) -> Option<Self::Entity>;
+}
+
+/// Composite objects declared `federation` that resolve entities with their members listed as
+/// `resolve_reference`, implemented by [`composite_object`](crate::composite_object).
+///
+/// juniper rejects objects as values of custom scalars, the `_Any` representations included, so
+/// the `_entities` field itself is left to the application, which can convert the
+/// representations it receives with [`Representation::try_from`] and resolve them with
+/// [`resolve_entities`](ResolveEntities::resolve_entities).
+pub trait ResolveEntities<S = DefaultScalarValue>: GraphQLValue<S>
+where
+    S: ScalarValue,
+{
+    /// Type of the entities, the one of the first member resolving references.
+    type Entity;
+
+    /// Resolves the entity `representation` refers to with the first member that resolves it.
+    fn resolve_entity(
+        context: &Self::Context,
+        representation: &Representation<S>,
+    ) -> Option<Self::Entity>;
+
+    /// Resolves `representations` in order, as `_entities` returns them, with `None` for the ones
+    /// no member resolves.
+    fn resolve_entities(
+        context: &Self::Context,
+        representations: &[Representation<S>],
+    ) -> Vec<Option<Self::Entity>> {
+        representations
+            .iter()
+            .map(|representation| Self::resolve_entity(context, representation))
+            .collect()
+    }
+}
+
+/// Name of the field of a representation holding the type of the entity.
+const TYPENAME_FIELD: &str = "__typename";
+
+/// Name of [Federation], which isn't registered in the schema since only its fields are composed.
+const FEDERATION_TYPE: &str = "_Federation";
+
+/// Name of the field returning the `_Service` type.
+pub(crate) const SERVICE_FIELD: &str = "_service";
+
+/// Name of the type of the `_service` field.
+pub(crate) const SERVICE_TYPE: &str = "_Service";
+
+/// The `_Service` type, whose `sdl` field returns the SDL of the subgraph.
+struct Service<C>(PhantomData<fn() -> C>);
+
+impl<C, S> GraphQLType<S> for Service<C>
+where
+    S: ScalarValue,
+{
+    fn name((): &Self::TypeInfo) -> Option<&str> {
+        Some(SERVICE_TYPE)
+    }
+
+    fn meta<'r>((): &Self::TypeInfo, registry: &mut Registry<'r, S>) -> MetaType<'r, S>
+    where
+        S: 'r,
+    {
+        let fields = [registry.field::<String>("sdl", &())];
+        registry.build_object_type::<Self>(&(), &fields).into_meta()
+    }
+}
+
+impl<C, S> GraphQLValue<S> for Service<C>
+where
+    S: ScalarValue,
+{
+    type Context = C;
+    type TypeInfo = ();
+
+    fn type_name<'i>(&self, info: &'i Self::TypeInfo) -> Option<&'i str> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+
+    fn resolve_field(
+        &self,
+        (): &Self::TypeInfo,
+        field_name: &str,
+        _: &Arguments<'_, S>,
+        executor: &Executor<'_, '_, Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        if field_name == "sdl" {
+            Ok(Value::scalar(crate::sdl::subgraph_sdl(executor.schema())))
+        } else {
+            Err(unknown_field(field_name, SERVICE_TYPE))
+        }
+    }
+}
+
+fn unknown_field<S>(field_name: &str, type_name: &str) -> FieldError<S> {
+    FieldError::from(format!(
+        "Field `{field_name}` not found on type `{type_name}`"
+    ))
+}
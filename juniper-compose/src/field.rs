@@ -0,0 +1,239 @@
+//! Metadata of the fields of composable types.
+
+use crate::type_to_owned;
+use juniper::{GraphQLType, Registry, ScalarValue, Type};
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    sync::{Mutex, OnceLock, PoisonError},
+};
+
+/// A field that exists on a composable type, as returned by
+/// [`ComposableObject::fields_meta`](crate::ComposableObject::fields_meta).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComposableField {
+    /// Name of the field.
+    pub name: &'static str,
+    /// Description of the field, if it has one.
+    pub description: Option<String>,
+    /// GraphQL type of the field.
+    pub field_type: Type<'static>,
+    /// Whether the field is deprecated.
+    pub deprecated: bool,
+    /// Why the field is deprecated, if it is and a reason is given.
+    pub deprecation_reason: Option<String>,
+    /// Arguments of the field, in the order they are declared.
+    pub arguments: Vec<ComposableArgument>,
+}
+
+/// An argument of a [`ComposableField`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComposableArgument {
+    /// Name of the argument.
+    pub name: String,
+    /// Description of the argument, if it has one.
+    pub description: Option<String>,
+    /// GraphQL type of the argument.
+    pub arg_type: Type<'static>,
+    /// Default value of the argument, if it has one, written the way introspection returns it,
+    /// e.g. `{size: 10, after: null}`.
+    pub default_value: Option<String>,
+}
+
+/// GraphQL type of a field that exists on a composable type, as juniper describes it at compile
+/// time, listed in [`ComposableObject::FIELD_TYPES`](crate::ComposableObject::FIELD_TYPES).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldType {
+    /// Name of the type the field returns, without its list and non-null wrappers.
+    pub name: &'static str,
+    /// Names of the types a field of an interface may return in place of this type in its
+    /// implementers: the type itself, and the implementers or members of an interface or union.
+    pub sub_types: &'static [&'static str],
+    /// List and non-null wrappers of the type, encoded like juniper's `WrappedValue`: `1` for the
+    /// non-null type, followed by a digit for each wrapper from the innermost, `2` making it
+    /// nullable and `3` a list of it.
+    pub wrapped: u128,
+}
+
+impl FieldType {
+    /// Returns `true` if a field of this type in an interface may be implemented by a field of
+    /// type `implementer`: its type is one of the [`sub_types`](Self::sub_types), and it is
+    /// wrapped the same, except that it may be non-null where this type is nullable.
+    #[must_use]
+    pub const fn accepts(&self, implementer: &Self) -> bool {
+        juniper::macros::reflect::str_exists_in_arr(implementer.name, self.sub_types)
+            && juniper::macros::reflect::can_be_subtype(self.wrapped, implementer.wrapped)
+    }
+}
+
+/// Fields metadata of each composable type, keyed by the type and scalar value type.
+type Cache = HashMap<(TypeId, TypeId), &'static [ComposableField]>;
+
+/// A field name paired with its description, as returned by
+/// [`ComposableObject::describe_fields`](crate::ComposableObject::describe_fields).
+pub(crate) type FieldDescription = (&'static str, Option<&'static str>);
+
+/// A field name paired with the role it requires, as returned by
+/// [`ComposableObject::fields_auth`](crate::ComposableObject::fields_auth).
+type FieldAuth = (&'static str, Option<&'static str>);
+
+/// Field roles of each composable type, keyed by the type.
+type AuthCache = HashMap<TypeId, &'static [FieldAuth]>;
+
+/// Field descriptions of each composable type, keyed like [`Cache`].
+type DescriptionCache = HashMap<(TypeId, TypeId), &'static [FieldDescription]>;
+
+/// Field names of each composable type, keyed by the type.
+type NameCache = HashMap<TypeId, &'static [&'static str]>;
+
+/// Returns the field names `names` builds for `T`, for a composable type whose fields are only
+/// known at run time to return from
+/// [`ComposableObject::fields`](crate::ComposableObject::fields). Built once per type, then
+/// leaked.
+///
+/// A `static` in a generic function is shared by every instantiation of it, so generic types
+/// can't keep their names in a `OnceLock` of their own.
+pub fn dynamic_fields<T>(names: impl FnOnce() -> Vec<String>) -> &'static [&'static str]
+where
+    T: ?Sized + 'static,
+{
+    static CACHE: OnceLock<Mutex<NameCache>> = OnceLock::new();
+
+    let cache = CACHE.get_or_init(Mutex::default);
+    if let Some(names) = cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&TypeId::of::<T>())
+    {
+        return names;
+    }
+
+    // Building the names may build those of another type, so the cache must not be locked in
+    // the meantime.
+    let names = names()
+        .into_iter()
+        .map(|name| &*Box::leak(name.into_boxed_str()))
+        .collect::<Vec<_>>();
+    cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| Box::leak(names.into_boxed_slice()))
+}
+
+/// Returns the fields and roles `fields_auth` builds for `T`, to return from
+/// [`ComposableObject::fields_auth`](crate::ComposableObject::fields_auth). Built once per type,
+/// then leaked, like [`dynamic_fields`].
+pub fn fields_auth<T>(
+    fields_auth: impl FnOnce() -> Vec<(String, Option<&'static str>)>,
+) -> &'static [FieldAuth]
+where
+    T: ?Sized + 'static,
+{
+    static CACHE: OnceLock<Mutex<AuthCache>> = OnceLock::new();
+
+    let cache = CACHE.get_or_init(Mutex::default);
+    if let Some(fields_auth) = cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&TypeId::of::<T>())
+    {
+        return fields_auth;
+    }
+
+    // Building the roles of a composite builds those of its members, so the cache must not be
+    // locked in the meantime.
+    let fields_auth = fields_auth()
+        .into_iter()
+        .map(|(name, role)| (&*Box::leak(name.into_boxed_str()), role))
+        .collect::<Vec<_>>();
+    cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| Box::leak(fields_auth.into_boxed_slice()))
+}
+
+/// Builds the metadata of `fields` of `T` from its meta type. Built once per type and scalar
+/// value type, then leaked.
+pub(crate) fn fields_meta<T, S>(fields: &'static [&'static str]) -> &'static [ComposableField]
+where
+    T: GraphQLType<S> + ?Sized + 'static,
+    T::TypeInfo: Default,
+    S: ScalarValue + 'static,
+{
+    static CACHE: OnceLock<Mutex<Cache>> = OnceLock::new();
+
+    let cache = CACHE.get_or_init(Mutex::default);
+    let key = (TypeId::of::<T>(), TypeId::of::<S>());
+    if let Some(fields_meta) = cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&key)
+    {
+        return fields_meta;
+    }
+
+    let mut registry = Registry::<S>::new(HashMap::default());
+    let meta = T::meta(&T::TypeInfo::default(), &mut registry);
+    let fields_meta = fields
+        .iter()
+        .map(|&name| {
+            let field = meta.field_by_name(name).unwrap_or_else(|| {
+                panic!(
+                    "Incorrect implementation of composable type {}: unknown field {}",
+                    T::name(&T::TypeInfo::default()).unwrap_or("<anonymous>"),
+                    name,
+                )
+            });
+            ComposableField {
+                name,
+                description: field.description.clone(),
+                field_type: type_to_owned(&field.field_type),
+                deprecated: field.deprecation_status.is_deprecated(),
+                deprecation_reason: field.deprecation_status.reason().map(str::to_owned),
+                arguments: field
+                    .arguments
+                    .iter()
+                    .flatten()
+                    .map(|argument| ComposableArgument {
+                        name: argument.name.clone(),
+                        description: argument.description.clone(),
+                        arg_type: type_to_owned(&argument.arg_type),
+                        default_value: argument.default_value.as_ref().map(ToString::to_string),
+                    })
+                    .collect(),
+            }
+        })
+        .collect::<Vec<_>>();
+    cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(key)
+        .or_insert_with(|| Box::leak(fields_meta.into_boxed_slice()))
+}
+
+/// Pairs the name of each of `fields_meta` of `T` with its description. Built once per type and
+/// scalar value type, then leaked.
+pub(crate) fn describe_fields<T, S>(
+    fields_meta: &'static [ComposableField],
+) -> &'static [FieldDescription]
+where
+    T: ?Sized + 'static,
+    S: 'static,
+{
+    static CACHE: OnceLock<Mutex<DescriptionCache>> = OnceLock::new();
+
+    let cache = CACHE.get_or_init(Mutex::default);
+    cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry((TypeId::of::<T>(), TypeId::of::<S>()))
+        .or_insert_with(|| {
+            let descriptions = fields_meta
+                .iter()
+                .map(|field| (field.name, field.description.as_deref()))
+                .collect::<Vec<_>>();
+            Box::leak(descriptions.into_boxed_slice())
+        })
+}
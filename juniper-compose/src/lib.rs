@@ -5,6 +5,11 @@
 //!
 //! [crates.io](https://crates.io/crates/juniper-compose) | [docs](https://docs.rs/juniper-compose) | [github](https://github.com/nikis05/juniper-compose)
 //!
+//! The code the macros generate refers to this crate as `juniper_compose`, so crates invoking
+//! them depend on it under that name:
+//! `juniper_compose = { package = "juniper_compose_ng", version = "0.17" }`. It only refers to
+//! public items, so composable types can be composed by another crate than the one defining them.
+//!
 //! ## Motivation
 //!
 //! You are building a GraphQL server using Juniper. At some point you realize that you have gigantic
@@ -102,15 +107,485 @@
 //! composite_object!(pub(crate) Query<Context = MyCustomContext>(UserQueries, TaskQueries));
 //! ```
 //!
-//! Custom scalars are currently not supported, but will be added if requested.
+//! Fields of a composed object can be exposed under a different name, e.g. to resolve a
+//! collision:
+//!
+//! ```ignore
+//! use juniper_compose_ng::composite_object;
+//!
+//! #[derive(Default)] struct UserQueries;
+//! #[derive(Default)] struct TaskQueries;
+//!
+//! // `TaskQueries::count` is exposed as `taskCount`
+//! composite_object!(Query(UserQueries, TaskQueries { count: task_count }));
+//! ```
+//!
+//! Or prefixed:
+//!
+//! ```ignore
+//! use juniper_compose_ng::composite_object;
+//!
+//! #[derive(Default)] struct UserQueries;
+//! #[derive(Default)] struct AdminQueries;
+//!
+//! // `AdminQueries::dashboard` is exposed as `adminDashboard`
+//! composite_object!(Query(UserQueries, prefix admin_ AdminQueries));
+//! ```
+//!
+//! Composite objects are composable themselves, so they can be nested:
+//!
+//! ```ignore
+//! use juniper_compose_ng::composite_object;
+//!
+//! #[derive(Default)] struct UserQueries;
+//! #[derive(Default)] struct TaskQueries;
+//! #[derive(Default)] struct AdminUserQueries;
+//! #[derive(Default)] struct AdminTaskQueries;
+//!
+//! composite_object!(Query(UserQueries, TaskQueries));
+//! composite_object!(AdminQuery(AdminUserQueries, AdminTaskQueries));
+//! composite_object!(RootQuery(Query, AdminQuery));
+//! ```
+//!
+//! Subscriptions are composed the same way, using
+//! [composable_subscription](composable_subscription) and
+//! [composite_subscription](composite_subscription):
+//!
+//! ```ignore
+//! use juniper_compose_ng::{composable_subscription, composite_subscription};
+//! use juniper::graphql_subscription;
+//!
+//! #[derive(Default)]
+//! struct UserSubscriptions;
+//!
+//! #[composable_subscription]
+//! #[graphql_subscription]
+//! impl UserSubscriptions {
+//!     // ...
+//! }
+//!
+//! #[derive(Default)]
+//! struct TaskSubscriptions;
+//!
+//! #[composable_subscription]
+//! #[graphql_subscription]
+//! impl TaskSubscriptions {
+//!     // ...
+//! }
+//!
+//! composite_subscription!(Subscription(UserSubscriptions, TaskSubscriptions));
+//! ```
+//!
+//! Custom scalar value types are supported:
+//!
+//! ```ignore
+//! use juniper_compose_ng::composite_object;
+//!
+//! struct MyCustomContext;
+//! #[derive(Default)] struct UserQueries;
+//! #[derive(Default)] struct TaskQueries;
+//! // `MyScalarValue` implements `juniper::ScalarValue`
+//!
+//! composite_object!(Query<Context = MyCustomContext, ScalarValue = MyScalarValue>(UserQueries, TaskQueries));
+//! ```
+//!
+//! Composable objects that don't specify `scalar = ...` in `#[graphql_object]`, or declare it
+//! generic with `scalar = S: ScalarValue`, can be composed with any scalar value type. Otherwise
+//! `ScalarValue` must match the one they are declared with. Objects whose type is generic over
+//! their scalar value type are listed with `_` in its place, which stands for the scalar value
+//! type of the composite:
+//!
+//! ```ignore
+//! use juniper_compose_ng::composite_object;
+//!
+//! #[derive(Default)] struct UserQueries;
+//! // `#[graphql_object(scalar = S)] impl<S: ScalarValue + Send + Sync> TaskQueries<S>`
+//! // `MyScalarValue` implements `juniper::ScalarValue`
+//!
+//! composite_object!(Query<ScalarValue = MyScalarValue>(UserQueries, TaskQueries<_>));
+//! ```
+//!
+//! Declared `generic_members`, a composite object lists every member written without generic
+//! arguments as if it were written with `<_>`, so that long lists of such members don't repeat
+//! it. Members written with generic arguments are left as they are, and ones that aren't generic
+//! are written with empty ones:
+//!
+//! ```ignore
+//! use juniper_compose_ng::composite_object;
+//!
+//! #[derive(Default)] struct UserQueries;
+//! // `TaskQueries<S>` and `ReportQueries<S>` are generic over their scalar value type
+//!
+//! composite_object!(Query<ScalarValue = MyScalarValue> generic_members (
+//!     UserQueries<>,
+//!     TaskQueries,
+//!     ReportQueries,
+//! ));
+//! ```
+//!
+//! Composite objects are implemented for `juniper::DefaultScalarValue` unless `ScalarValue` is
+//! given. Declaring a type parameter bound by `ScalarValue` instead implements them for every
+//! `Send + Sync` scalar value type, so that the same composite object can be part of schemas with
+//! different scalar value types. Its members must then be composable with any scalar value type,
+//! and can't be listed with `_`:
+//!
+//! ```ignore
+//! use juniper_compose_ng::composite_object;
+//!
+//! #[derive(Default)] struct UserQueries;
+//! #[derive(Default)] struct TaskQueries;
+//!
+//! composite_object!(Query<S: ScalarValue>(UserQueries, TaskQueries));
+//! ```
+//!
+//! Interfaces are composed from field definitions split across multiple
+//! `juniper::graphql_interface` traits, using [composable_interface](composable_interface) and
+//! [composite_interface](composite_interface):
+//!
+//! ```ignore
+//! use juniper_compose_ng::{composable_interface, composite_interface};
+//! use juniper::{graphql_interface, graphql_object};
+//!
+//! #[composable_interface]
+//! #[graphql_interface]
+//! trait Identifiable {
+//!     fn id(&self) -> &str;
+//! }
+//!
+//! #[composable_interface]
+//! #[graphql_interface]
+//! trait Timestamped {
+//!     fn created_at(&self) -> i32;
+//! }
+//!
+//! composite_interface!(Node(IdentifiableValue, TimestampedValue) for = (User, Task));
+//!
+//! struct User;
+//!
+//! #[graphql_object(impl = Node)]
+//! impl User {
+//!     // ...
+//! }
+//! ```
+//!
+//! Input objects are composed from `juniper::GraphQLInputObject` structs the same way, using
+//! [composable_input_object](composable_input_object) and
+//! [composite_input_object](composite_input_object):
+//!
+//! ```ignore
+//! use juniper_compose_ng::{composable_input_object, composite_input_object};
+//! use juniper::GraphQLInputObject;
+//!
+//! #[composable_input_object]
+//! #[derive(GraphQLInputObject)]
+//! struct UserFilter {
+//!     name: Option<String>,
+//! }
+//!
+//! #[composable_input_object]
+//! #[derive(GraphQLInputObject)]
+//! struct StatusFilter {
+//!     done: bool,
+//! }
+//!
+//! composite_input_object!(TaskFilter(UserFilter, StatusFilter));
+//! ```
+//!
+//! So are unions, from `juniper::GraphQLUnion` enums, using [composable_union](composable_union)
+//! and [composite_union](composite_union):
+//!
+//! ```ignore
+//! use juniper_compose_ng::{composable_union, composite_union};
+//! use juniper::GraphQLUnion;
+//!
+//! #[composable_union]
+//! #[derive(GraphQLUnion)]
+//! enum UserResults {
+//!     User(User),
+//! }
+//!
+//! #[composable_union]
+//! #[derive(GraphQLUnion)]
+//! enum TaskResults {
+//!     Task(Task),
+//! }
+//!
+//! composite_union!(SearchResult(UserResults, TaskResults));
+//! ```
+//!
+//! Composites whose members can't be listed in one invocation, e.g. because plugins contribute
+//! them, can be written by hand. [member_fields](member_fields) returns the fields of a member to
+//! build the meta type with, and [dispatch_field](dispatch_field) and
+//! [dispatch_field_async](dispatch_field_async) resolve a field with a member if it is one of its
+//! fields:
+//!
+//! ```ignore
+//! use juniper::{
+//!     Arguments, BoxFuture, DefaultScalarValue, ExecutionResult, Executor, GraphQLType,
+//!     GraphQLValue, GraphQLValueAsync, Registry, meta::MetaType,
+//! };
+//! use juniper_compose_ng::{dispatch_field, dispatch_field_async, member_fields, unknown_field};
+//!
+//! #[derive(Default)]
+//! struct Query {
+//!     users: UserQueries,
+//!     tasks: TaskQueries,
+//! }
+//!
+//! impl GraphQLType for Query {
+//!     fn name(_: &()) -> Option<&str> {
+//!         Some("Query")
+//!     }
+//!
+//!     fn meta<'r>(info: &(), registry: &mut Registry<'r>) -> MetaType<'r>
+//!     where
+//!         DefaultScalarValue: 'r,
+//!     {
+//!         let mut fields = member_fields::<UserQueries, _>(info, registry);
+//!         fields.extend(member_fields::<TaskQueries, _>(info, registry));
+//!         registry.build_object_type::<Self>(info, &fields).into_meta()
+//!     }
+//! }
+//!
+//! impl GraphQLValue for Query {
+//!     type Context = ();
+//!     type TypeInfo = ();
+//!
+//!     fn type_name<'i>(&self, info: &'i ()) -> Option<&'i str> {
+//!         Self::name(info)
+//!     }
+//!
+//!     fn resolve_field(
+//!         &self,
+//!         info: &(),
+//!         field_name: &str,
+//!         arguments: &Arguments,
+//!         executor: &Executor<()>,
+//!     ) -> ExecutionResult {
+//!         dispatch_field(&self.users, info, field_name, arguments, executor)
+//!             .or_else(|| dispatch_field(&self.tasks, info, field_name, arguments, executor))
+//!             .unwrap_or_else(|| Err(unknown_field("Query", field_name)))
+//!     }
+//! }
+//!
+//! impl GraphQLValueAsync for Query {
+//!     fn resolve_field_async<'a>(
+//!         &'a self,
+//!         info: &'a (),
+//!         field_name: &'a str,
+//!         arguments: &'a Arguments,
+//!         executor: &'a Executor<()>,
+//!     ) -> BoxFuture<'a, ExecutionResult> {
+//!         dispatch_field_async(&self.users, info, field_name, arguments, executor)
+//!             .or_else(|| dispatch_field_async(&self.tasks, info, field_name, arguments, executor))
+//!             .unwrap_or_else(|| Box::pin(async move { Err(unknown_field("Query", field_name)) }))
+//!     }
+//! }
+//! ```
+//!
+//! Unlike composites generated by the macros, composites written by hand:
+//!
+//! - don't check that their members' fields are disjoint, which
+//!   [assert_fields_disjoint](assert_fields_disjoint) can do at compile time;
+//! - can't rename, prefix, exclude or select their members' fields;
+//! - don't cache their merged fields, so the meta type is built from the members' every time;
+//! - only compose types they name, since [ComposableObject](ComposableObject) has associated
+//!   constants and can't be used as a trait object;
+//! - aren't composable themselves until they implement [ComposableObject](ComposableObject),
+//!   listing the fields of their members.
+//!
+//! Any juniper object can be composed, including one implementing the juniper traits by hand
+//! rather than with `#[graphql_object]`, which [composable_object](composable_object) can't read
+//! the fields of. It implements [ComposableObject](ComposableObject) by hand instead, with the
+//! name of its type and the GraphQL names of its fields, and is listed in
+//! [composite_object](composite_object) like any other:
+//!
+//! ```
+//! # extern crate juniper_compose_ng as juniper_compose;
+//! use juniper::{
+//!     Arguments, BoxFuture, DefaultScalarValue, EmptyMutation, EmptySubscription,
+//!     ExecutionResult, Executor, GraphQLType, GraphQLValue, GraphQLValueAsync, Registry,
+//!     RootNode, Value, Variables, graphql_object, graphql_value, meta::MetaType,
+//! };
+//! use juniper_compose::{ComposableObject, composable_object, composite_object, unknown_field};
+//!
+//! #[derive(Default)]
+//! struct Version;
+//!
+//! impl GraphQLType for Version {
+//!     fn name(_: &()) -> Option<&str> {
+//!         Some("Version")
+//!     }
+//!
+//!     fn meta<'r>(info: &(), registry: &mut Registry<'r>) -> MetaType<'r>
+//!     where
+//!         DefaultScalarValue: 'r,
+//!     {
+//!         let fields = [registry.field::<String>("version", info)];
+//!         registry.build_object_type::<Self>(info, &fields).into_meta()
+//!     }
+//! }
+//!
+//! impl GraphQLValue for Version {
+//!     type Context = ();
+//!     type TypeInfo = ();
+//!
+//!     fn type_name<'i>(&self, info: &'i ()) -> Option<&'i str> {
+//!         Self::name(info)
+//!     }
+//!
+//!     fn resolve_field(
+//!         &self,
+//!         _: &(),
+//!         field_name: &str,
+//!         _: &Arguments,
+//!         _: &Executor<()>,
+//!     ) -> ExecutionResult {
+//!         match field_name {
+//!             "version" => Ok(Value::scalar("1.0")),
+//!             _ => Err(unknown_field("Version", field_name)),
+//!         }
+//!     }
+//! }
+//!
+//! impl GraphQLValueAsync for Version {
+//!     fn resolve_field_async<'a>(
+//!         &'a self,
+//!         info: &'a (),
+//!         field_name: &'a str,
+//!         arguments: &'a Arguments,
+//!         executor: &'a Executor<()>,
+//!     ) -> BoxFuture<'a, ExecutionResult> {
+//!         let result = self.resolve_field(info, field_name, arguments, executor);
+//!         Box::pin(async move { result })
+//!     }
+//! }
+//!
+//! impl ComposableObject for Version {
+//!     const TYPE_NAME: &'static str = "Version";
+//!     const FIELDS: &'static [&'static str] = &["version"];
+//! }
+//!
+//! #[derive(Default)]
+//! struct UserQueries;
+//!
+//! #[composable_object]
+//! #[graphql_object]
+//! impl UserQueries {
+//!     fn user_count() -> i32 {
+//!         2
+//!     }
+//! }
+//!
+//! composite_object!(Query(Version, UserQueries));
+//!
+//! let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+//! let (data, errors) =
+//!     juniper::execute_sync("{ version userCount }", None, &schema, &Variables::new(), &())
+//!         .unwrap();
+//! assert!(errors.is_empty());
+//! assert_eq!(data, graphql_value!({ "version": "1.0", "userCount": 2 }));
+//! ```
+//!
+//! Every field listed in [FIELDS](ComposableObject::FIELDS) must exist on its meta type, or
+//! building the meta type of a composite of it panics, naming the field. Fields that are only
+//! resolved asynchronously are listed in [`ASYNC_FIELDS`](ComposableObject::ASYNC_FIELDS) as well.
+//! [`FIELD_TYPES`](ComposableObject::FIELD_TYPES) may be left empty, which skips checking the types
+//! of its fields against the interfaces of the composite objects composing it.
+//! `#[composable_object]` applied to an impl of a juniper trait fails to compile, pointing here.
 
-use juniper::{GraphQLTypeAsync, Type};
+use juniper::{
+    DefaultScalarValue, FromInputValue, GraphQLSubscriptionType, GraphQLType, GraphQLTypeAsync,
+    GraphQLValue, ScalarValue, ToInputValue, Type,
+};
 use std::borrow::Cow;
 
+pub use dispatch::{dispatch_field, dispatch_field_async, member_fields, unknown_field};
+pub use federation::{Federation, Representation, ResolveEntities, ResolveReference};
+pub use field::{ComposableArgument, ComposableField, FieldType, dynamic_fields, fields_auth};
+pub use sdl::{root_node_sdl, schema_sdl};
+
+#[doc(hidden)]
+pub mod cache;
+#[doc(hidden)]
+pub mod check;
+#[doc(hidden)]
+pub mod dispatch;
+mod federation;
+mod field;
+#[doc(hidden)]
+pub mod member;
+#[cfg(feature = "inventory")]
+pub mod registry;
+mod sdl;
+
+#[cfg(feature = "inventory")]
+#[doc(hidden)]
+pub use inventory;
+
 /// Implements [ComposableObject](ComposableObject) for a GraphQL object type.
 /// **Important**: must be applied before the `juniper::graphql_object` macro.
 ///
-/// ## Example
+/// Fields are listed under the names juniper gives them: the one given with
+/// `#[graphql(name = "...")]`, or the name of the method renamed by the `rename_all` argument of
+/// `#[graphql_object]` (camelCase by default). Methods juniper ignores, marked
+/// `#[graphql(ignore)]` (or `skip`), aren't listed, so they are left out of composites as well:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{ComposableObject, composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         Self::count()
+///     }
+///
+///     #[graphql(name = "profile")]
+///     fn current_user_profile() -> &'static str {
+///         "Ada"
+///     }
+///
+///     #[graphql(ignore)]
+///     fn count() -> i32 {
+///         3
+///     }
+/// }
+///
+/// composite_object!(Query(UserQueries));
+///
+/// assert_eq!(<UserQueries as ComposableObject>::FIELDS, ["userCount", "profile"]);
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let variables = Variables::new();
+/// let query = r#"{ __type(name: "Query") { fields { name } } }"#;
+/// let (value, _) = juniper::execute_sync(query, None, &schema, &variables, &()).unwrap();
+/// assert_eq!(
+///     value,
+///     graphql_value!({ "__type": { "fields": [
+///         { "name": "userCount" },
+///         { "name": "profile" },
+///     ] } }),
+/// );
+///
+/// let (value, errors) =
+///     juniper::execute_sync("{ userCount profile }", None, &schema, &variables, &()).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(value, graphql_value!({ "userCount": 3, "profile": "Ada" }));
+/// ```
+///
+/// Composite objects construct their members with [Default](Default).
+/// `#[composable_object(default)]` implements it for an object without fields, so that it doesn't
+/// need `#[derive(Default)]`.
+///
+/// ## Examples
 ///
 /// ```ignore
 /// use juniper_compose_ng::composable_object;
@@ -123,48 +598,3246 @@ use std::borrow::Cow;
 /// impl UserQueries {
 ///     // ...
 /// }
+///
+/// struct TaskQueries;
+///
+/// #[composable_object(default)]
+/// #[graphql_object]
+/// impl TaskQueries {
+///     // ...
+/// }
 /// ```
 pub use juniper_compose_macros_ng::composable_object;
 
 /// Composes an object type from multiple [ComposableObject](ComposableObject)s.
 /// Custom context type may be specified, otherwise defaults to `()`.
-/// Custom visibility fro generated type may be specified.
+/// Custom type info may be specified with `Info = ...`, otherwise defaults to `()`. It is passed
+/// to every composed object, which must have the same type info.
+/// juniper passes it to the meta type and the resolvers of the composite, which pass it on to their
+/// members, so a schema built with `RootNode::new_with_info` hands its query type info to every
+/// member. The value to build the schema with may be given after the members with `info = expr`,
+/// which the generated type returns from its `info()` function, evaluating `expr` every time it's
+/// called. A struct literal needs parentheses there, since braces start inline fields:
 ///
-/// ## Examples
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     Arguments, BoxFuture, DefaultScalarValue, EmptyMutation, EmptySubscription,
+///     ExecutionResult, Executor, GraphQLType, GraphQLValue, GraphQLValueAsync, Registry,
+///     RootNode, Value, Variables, graphql_value, meta::MetaType,
+/// };
+/// use juniper_compose::{ComposableObject, composite_object, unknown_field};
 ///
-/// ```ignore
-/// use juniper_compose_ng::composite_object;
+/// struct SchemaInfo {
+///     greeting: String,
+/// }
 ///
-/// #[derive(Default)] struct UserQueries;
-/// #[derive(Default)] struct TaskQueries;
-/// #[derive(Default)] struct UserMutations;
-/// #[derive(Default)] struct TaskMutations;
-/// struct MyContextType;
+/// fn build_info() -> SchemaInfo {
+///     SchemaInfo { greeting: "Hello".into() }
+/// }
 ///
-/// composite_object!(Query(UserQueries, TaskQueries));
-/// composite_object!(Mutation<Context = MyContextType>(UserMutations, TaskMutations));
-/// composite_object!(pub QueryPublic(UserQueries, TaskQueries));
+/// #[derive(Default)]
+/// struct Greeting;
+///
+/// impl GraphQLType for Greeting {
+///     fn name(_: &SchemaInfo) -> Option<&str> {
+///         Some("Greeting")
+///     }
+///
+///     fn meta<'r>(info: &SchemaInfo, registry: &mut Registry<'r>) -> MetaType<'r>
+///     where
+///         DefaultScalarValue: 'r,
+///     {
+///         let fields = [registry.field::<String>("greeting", &())];
+///         registry.build_object_type::<Self>(info, &fields).into_meta()
+///     }
+/// }
+///
+/// impl GraphQLValue for Greeting {
+///     type Context = ();
+///     type TypeInfo = SchemaInfo;
+///
+///     fn type_name<'i>(&self, info: &'i SchemaInfo) -> Option<&'i str> {
+///         Self::name(info)
+///     }
+///
+///     fn resolve_field(
+///         &self,
+///         info: &SchemaInfo,
+///         field_name: &str,
+///         _: &Arguments,
+///         _: &Executor<()>,
+///     ) -> ExecutionResult {
+///         match field_name {
+///             "greeting" => Ok(Value::scalar(info.greeting.clone())),
+///             _ => Err(unknown_field("Greeting", field_name)),
+///         }
+///     }
+/// }
+///
+/// impl GraphQLValueAsync for Greeting {
+///     fn resolve_field_async<'a>(
+///         &'a self,
+///         info: &'a SchemaInfo,
+///         field_name: &'a str,
+///         arguments: &'a Arguments,
+///         executor: &'a Executor<()>,
+///     ) -> BoxFuture<'a, ExecutionResult> {
+///         let result = self.resolve_field(info, field_name, arguments, executor);
+///         Box::pin(async move { result })
+///     }
+/// }
+///
+/// impl ComposableObject for Greeting {
+///     const TYPE_NAME: &'static str = "Greeting";
+///     const FIELDS: &'static [&'static str] = &["greeting"];
+/// }
+///
+/// composite_object!(Query<Info = SchemaInfo>(Greeting) info = build_info());
+///
+/// let schema = RootNode::new_with_info(
+///     Query,
+///     EmptyMutation::new(),
+///     EmptySubscription::new(),
+///     Query::info(),
+///     (),
+///     (),
+/// );
+/// let (data, errors) =
+///     juniper::execute_sync("{ greeting }", None, &schema, &Variables::new(), &()).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(data, graphql_value!({ "greeting": "Hello" }));
 /// ```
-pub use juniper_compose_macros_ng::composite_object;
-
-/// Object types that you want to compose into one must implement this trait.
-/// Use [composable_object](composable_object) to implement it.
-pub trait ComposableObject: GraphQLTypeAsync + Default
-where
-    Self::Context: Sync,
-    Self::TypeInfo: Sync,
-{
-    /// Returns a list of fields that exist on this object type.
-    fn fields() -> &'static [&'static str];
-}
-
-#[doc(hidden)]
-#[allow(clippy::must_use_candidate)]
-pub fn type_to_owned(ty: &Type<'_>) -> Type<'static> {
+///
+/// Custom scalar value type may be specified, otherwise defaults to
+/// [`DefaultScalarValue`](juniper::DefaultScalarValue). `_` in the type of a composed object
+/// stands for it, which instantiates objects generic over their scalar value type. Juniper can't
+/// mix scalar value types in a schema, so a composed object declared with another one fails to
+/// compile with an error naming it and the scalar value type of the composite.
+/// Custom visibility for generated type may be specified, in any form Rust accepts (`pub`,
+/// `pub(crate)`, `pub(super)`, `pub(in path)`). The associated functions of the generated type
+/// have the same visibility.
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// mod schema {
+///     pub mod users {
+///         use juniper::graphql_object;
+///         use juniper_compose::{composable_object, composite_object};
+///
+///         #[derive(Default)]
+///         pub struct UserQueries;
+///
+///         #[composable_object]
+///         #[graphql_object]
+///         impl UserQueries {
+///             fn user_count() -> i32 {
+///                 2
+///             }
+///         }
+///
+///         composite_object!(pub(super) Query(UserQueries));
+///         composite_object!(pub(in crate::schema) AdminQuery(prefix admin UserQueries));
+///     }
+///
+///     pub fn field_origins() -> [Option<&'static str>; 2] {
+///         [
+///             users::Query::field_origin("userCount"),
+///             users::AdminQuery::field_origin("adminUserCount"),
+///         ]
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(schema::field_origins(), [Some("UserQueries"); 2]);
+/// }
+/// ```
+///
+/// Declared `generic_members`, the composite object lists every composed object written without
+/// generic arguments as if it were written with `<_>`, instantiating it with its scalar value
+/// type. Composed objects written with generic arguments are left as they are, so one that isn't
+/// generic is written with empty ones:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     DefaultScalarValue, EmptyMutation, EmptySubscription, RootNode, ScalarValue, Variables,
+///     graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+/// use std::marker::PhantomData;
+///
+/// struct UserQueries<S>(PhantomData<S>);
+///
+/// impl<S> Default for UserQueries<S> {
+///     fn default() -> Self {
+///         Self(PhantomData)
+///     }
+/// }
+///
+/// #[composable_object]
+/// #[graphql_object(scalar = S)]
+/// impl<S: ScalarValue + Send + Sync> UserQueries<S> {
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// struct TaskQueries<S>(PhantomData<S>);
+///
+/// impl<S> Default for TaskQueries<S> {
+///     fn default() -> Self {
+///         Self(PhantomData)
+///     }
+/// }
+///
+/// #[composable_object]
+/// #[graphql_object(scalar = S)]
+/// impl<S: ScalarValue + Send + Sync> TaskQueries<S> {
+///     fn task_count() -> i32 {
+///         3
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct VersionQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl VersionQueries {
+///     fn version() -> &'static str {
+///         "1.0"
+///     }
+/// }
+///
+/// // Same as `(UserQueries<_>, TaskQueries<_>, VersionQueries)`
+/// composite_object!(Query<ScalarValue = DefaultScalarValue> generic_members (
+///     UserQueries,
+///     TaskQueries<_>,
+///     VersionQueries<>,
+/// ));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let query = "{ userCount taskCount version }";
+/// let (data, errors) =
+///     juniper::execute_sync(query, None, &schema, &Variables::new(), &()).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(data, graphql_value!({ "userCount": 2, "taskCount": 3, "version": "1.0" }));
+/// ```
+///
+/// Field names are checked for collisions at compile time: if two composed objects define a
+/// field with the same (case-sensitive) name, the invocation fails to compile with an error
+/// naming the field and every composed object defining it. The names of all the composed objects
+/// are checked in a single pass, and computing an exposed name takes time linear in its length,
+/// so composites of many members with long names compile quickly, e.g. eight members of twelve
+/// fields each:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object};
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// macro_rules! queries {
+///     ($name:ident { $( $field:ident ),* $(,)? }) => {
+///         #[derive(Default)]
+///         struct $name;
+///
+///         #[composable_object]
+///         #[graphql_object]
+///         impl $name {
+///             $( fn $field() -> i32 { stringify!($field).len() as i32 } )*
+///         }
+///     };
+/// }
+///
+/// queries!(AccountQueries {
+///     account_balance_in_cents, account_created_at_timestamp, account_owner_display_name,
+///     account_primary_email_address, account_two_factor_enabled, account_last_login_timestamp,
+///     account_subscription_plan_name, account_outstanding_invoice_count,
+///     account_billing_country_code, account_referral_program_code,
+///     account_notification_channel_count, account_storage_quota_in_bytes,
+/// });
+/// queries!(ProjectQueries {
+///     project_member_count, project_open_issue_count, project_closed_issue_count,
+///     project_default_branch_name, project_last_deployment_timestamp,
+///     project_continuous_integration_status, project_code_coverage_percentage,
+///     project_pending_review_count, project_archived_repository_count,
+///     project_release_candidate_name, project_dependency_update_count,
+///     project_security_advisory_count,
+/// });
+/// queries!(BillingQueries {
+///     billing_current_period_start, billing_current_period_end, billing_next_invoice_amount,
+///     billing_payment_method_brand, billing_payment_method_last_four,
+///     billing_tax_identification_number, billing_applied_discount_percentage,
+///     billing_failed_payment_attempt_count, billing_refunded_amount_in_cents,
+///     billing_credit_balance_in_cents, billing_usage_based_charge_count,
+///     billing_invoice_delivery_email_count,
+/// });
+/// queries!(AnalyticsQueries {
+///     analytics_daily_active_user_count, analytics_weekly_active_user_count,
+///     analytics_monthly_active_user_count, analytics_average_session_length_seconds,
+///     analytics_bounce_rate_percentage, analytics_conversion_funnel_step_count,
+///     analytics_retained_user_count_after_week, analytics_churned_user_count,
+///     analytics_page_view_count, analytics_unique_visitor_count,
+///     analytics_tracked_event_type_count, analytics_experiment_variant_count,
+/// });
+/// # queries!(SupportQueries {
+/// #     support_open_ticket_count, support_average_first_response_minutes,
+/// #     support_escalated_ticket_count, support_customer_satisfaction_score,
+/// #     support_pending_ticket_count, support_resolved_ticket_count_today,
+/// #     support_assigned_agent_count, support_knowledge_base_article_count,
+/// #     support_macro_template_count, support_reopened_ticket_count,
+/// #     support_breached_agreement_count, support_unassigned_ticket_count,
+/// # });
+/// # queries!(InventoryQueries {
+/// #     inventory_stock_keeping_unit_count, inventory_reserved_item_count,
+/// #     inventory_backordered_item_count, inventory_warehouse_location_count,
+/// #     inventory_reorder_threshold_count, inventory_damaged_item_count,
+/// #     inventory_incoming_shipment_count, inventory_outgoing_shipment_count,
+/// #     inventory_average_restock_days, inventory_discontinued_product_count,
+/// #     inventory_supplier_contact_count, inventory_cycle_count_discrepancies,
+/// # });
+/// # queries!(ShippingQueries {
+/// #     shipping_carrier_account_count, shipping_label_printed_count,
+/// #     shipping_delivered_parcel_count, shipping_returned_parcel_count,
+/// #     shipping_average_transit_days, shipping_customs_declaration_count,
+/// #     shipping_lost_parcel_claim_count, shipping_pickup_location_count,
+/// #     shipping_insured_parcel_count, shipping_international_parcel_count,
+/// #     shipping_rate_quote_request_count, shipping_delayed_parcel_count,
+/// # });
+/// # queries!(SecurityQueries {
+/// #     security_failed_login_attempt_count, security_active_session_count,
+/// #     security_revoked_token_count, security_api_key_rotation_days,
+/// #     security_audit_log_entry_count, security_blocked_address_count,
+/// #     security_password_reset_request_count, security_trusted_device_count,
+/// #     security_single_sign_on_provider_count, security_open_incident_count,
+/// #     security_role_assignment_count, security_permission_grant_count,
+/// # });
+///
+/// composite_object!(Query(
+///     AccountQueries,
+///     ProjectQueries,
+///     BillingQueries,
+///     AnalyticsQueries,
+/// #   SupportQueries,
+/// #   InventoryQueries,
+/// #   ShippingQueries,
+/// #   SecurityQueries,
+///     // ...
+/// ));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let query = "{ accountBalanceInCents analyticsExperimentVariantCount }";
+/// let (data, errors) =
+///     juniper::execute_sync(query, None, &schema, &Variables::new(), &()).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(
+///     data,
+///     juniper::graphql_value!({
+///         "accountBalanceInCents": 24,
+///         "analyticsExperimentVariantCount": 34,
+///     }),
+/// );
+/// ```
+///
+/// Two composed objects referring to different types of the same name, e.g. `Edge` objects of
+/// two modules, can't be detected at compile time. Juniper registers the first type of a name and
+/// ignores the others, so the fields of the second composed object would silently refer to the
+/// type of the first. Building the meta type of the composite panics instead, naming the type,
+/// both composed objects and the shapes they register it with. Types of the same name and shape,
+/// including the same type referred to twice, are fine:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{EmptyMutation, EmptySubscription, RootNode, graphql_object};
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// mod users {
+///     #[derive(juniper::GraphQLObject)]
+///     pub struct Edge {
+///         pub cursor: String,
+///         pub user_id: i32,
+///     }
+/// }
+///
+/// mod tasks {
+///     #[derive(juniper::GraphQLObject)]
+///     pub struct Edge {
+///         pub cursor: String,
+///         pub task_id: i32,
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn users() -> Vec<users::Edge> {
+///         vec![]
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct TaskQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl TaskQueries {
+///     fn tasks() -> Vec<tasks::Edge> {
+///         vec![]
+///     }
+/// }
+///
+/// composite_object!(Query(UserQueries, TaskQueries));
+///
+/// let panic = std::panic::catch_unwind(|| {
+///     RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+/// })
+/// .unwrap_err();
+/// assert_eq!(
+///     panic.downcast_ref::<String>().unwrap(),
+///     "Conflicting type in composed objects of Query: UserQueries registers `Edge` as \
+///      `type { cursor: String!, userId: Int! }`, but TaskQueries registers it as \
+///      `type { cursor: String!, taskId: Int! }`",
+/// );
+/// ```
+///
+/// Fields keep their descriptions, deprecations and arguments. Arguments keep their names, types,
+/// descriptions and default values, input object defaults included. A composite object and the
+/// object it splits up introspect the same:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, GraphQLInputObject, RootNode, Variables, graphql_object,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(GraphQLInputObject)]
+/// struct Page {
+///     /// Number of tasks per page.
+///     #[graphql(default = 20)]
+///     size: i32,
+///     after: Option<String>,
+/// }
+///
+/// struct Monolithic;
+///
+/// #[graphql_object(name = "Query")]
+/// impl Monolithic {
+///     /// Looks a user up.
+///     fn user(#[graphql(description = "Id of the user.")] id: i32) -> Option<i32> {
+///         Some(id)
+///     }
+///
+///     #[graphql(deprecated = "Use `tasks`.")]
+///     fn all_tasks(#[graphql(default = Page { size: 10, after: None })] page: Page) -> Vec<i32> {
+///         vec![page.size]
+///     }
+///
+///     fn tasks(page: Option<Page>, #[graphql(default = true)] open: bool) -> Vec<i32> {
+///         vec![page.map_or(0, |page| page.size), i32::from(open)]
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     /// Looks a user up.
+///     fn user(#[graphql(description = "Id of the user.")] id: i32) -> Option<i32> {
+///         Some(id)
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct TaskQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl TaskQueries {
+///     #[graphql(deprecated = "Use `tasks`.")]
+///     fn all_tasks(#[graphql(default = Page { size: 10, after: None })] page: Page) -> Vec<i32> {
+///         vec![page.size]
+///     }
+///
+///     fn tasks(page: Option<Page>, #[graphql(default = true)] open: bool) -> Vec<i32> {
+///         vec![page.map_or(0, |page| page.size), i32::from(open)]
+///     }
+/// }
+///
+/// composite_object!(Query(UserQueries, TaskQueries));
+///
+/// const INTROSPECTION: &str = r#"{
+///     query: __type(name: "Query") {
+///         name
+///         fields(includeDeprecated: true) {
+///             name description isDeprecated deprecationReason type { ...TypeRef }
+///             args { name description defaultValue type { ...TypeRef } }
+///         }
+///     }
+///     page: __type(name: "Page") {
+///         inputFields { name description defaultValue type { ...TypeRef } }
+///     }
+/// }
+/// fragment TypeRef on __Type {
+///     kind name ofType { kind name ofType { kind name ofType { kind name } } }
+/// }"#;
+///
+/// let monolithic = RootNode::new(Monolithic, EmptyMutation::new(), EmptySubscription::new());
+/// let composite = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let variables = Variables::new();
+/// let expected = juniper::execute_sync(INTROSPECTION, None, &monolithic, &variables, &());
+/// assert!(expected.as_ref().is_ok_and(|(_, errors)| errors.is_empty()));
+/// assert_eq!(
+///     juniper::execute_sync(INTROSPECTION, None, &composite, &variables, &()),
+///     expected,
+/// );
+/// ```
+///
+/// Every composed object must implement [ComposableObject](ComposableObject): a type missing
+/// `#[composable_object]` is reported at the point it is listed, with a note suggesting it.
+/// These errors come first, one for every member that fails, each naming the member; a type that
+/// isn't a GraphQL object at all is reported as not composable rather than for its context and
+/// type info as well.
+///
+/// ```compile_fail
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::graphql_object;
+/// use juniper_compose::composite_object;
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// // error: `UserQueries` is not a composable object resolved with the scalar value type
+/// // `DefaultScalarValue`
+/// // note: apply `#[composable_object]` before `#[graphql_object]` to make it composable
+/// composite_object!(Query(UserQueries));
+/// ```
+///
+/// Every composed object must have the context of the composite object, including the default
+/// `()`. A composed object with a different context is reported at the point it is listed. Its
+/// context is the one juniper gives it, whether declared with `#[graphql_object(context = ...)]`
+/// or inferred from the arguments of its resolvers, so resolvers are free not to take it. Contexts
+/// are compared as types rather than as written, so a composed object declaring
+/// `context = ()` composes into a composite object without `Context`:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object(context = ())]
+/// impl UserQueries {
+///     fn user_count(_context: &()) -> i32 {
+///         2
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct TaskQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl TaskQueries {
+///     fn task_count() -> i32 {
+///         3
+///     }
+/// }
+///
+/// composite_object!(Query(UserQueries, TaskQueries));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let (data, errors) =
+///     juniper::execute_sync("{ userCount taskCount }", None, &schema, &Variables::new(), &())
+///         .unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(data, graphql_value!({ "userCount": 2, "taskCount": 3 }));
+/// ```
+///
+/// The context must be `Sync`, even if queries are only executed synchronously: juniper's object
+/// macros implement asynchronous resolution for every object, which shares the context between
+/// executors, and so do composite objects. A context that isn't is reported at `Context = ...`.
+/// Wrapping the state it mutates in a `Mutex`, a `RwLock` or atomics, rather than a `Cell` or
+/// `RefCell`, makes it `Sync`.
+/// The context is only ever borrowed: the executor is handed to the composed objects as is, and
+/// `from_context` constructors get a reference to the context, so it is never cloned:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{ComposableFromContext, composable_object, composite_object};
+/// use std::{
+///     pin::pin,
+///     task::{self, Poll, Waker},
+/// };
+///
+/// pub struct Context {
+///     user_count: i32,
+/// }
+///
+/// impl Clone for Context {
+///     fn clone(&self) -> Self {
+///         panic!("the context is cloned")
+///     }
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object(context = Context)]
+/// impl UserQueries {
+///     fn user_count(context: &Context) -> i32 {
+///         context.user_count
+///     }
+/// }
+///
+/// struct TaskQueries {
+///     task_count: i32,
+/// }
+///
+/// impl ComposableFromContext for TaskQueries {
+///     fn from_context(context: &Context) -> Self {
+///         Self { task_count: context.user_count + 1 }
+///     }
+/// }
+///
+/// #[composable_object]
+/// #[graphql_object(context = Context)]
+/// impl TaskQueries {
+///     fn task_count(&self) -> i32 {
+///         self.task_count
+///     }
+/// }
+///
+/// composite_object!(Query<Context = Context>(UserQueries, from_context TaskQueries));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let context = Context { user_count: 2 };
+/// let (query, variables) = ("{ userCount taskCount }", Variables::new());
+/// let (data, errors) = juniper::execute_sync(query, None, &schema, &variables, &context).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(data, graphql_value!({ "userCount": 2, "taskCount": 3 }));
+///
+/// // The resolvers never wait, so polling once completes the asynchronous execution.
+/// let future = pin!(juniper::execute(query, None, &schema, &variables, &context));
+/// let Poll::Ready(result) = future.poll(&mut task::Context::from_waker(Waker::noop())) else {
+///     panic!("the execution is pending");
+/// };
+/// let (data, errors) = result.unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(data, graphql_value!({ "userCount": 2, "taskCount": 3 }));
+/// ```
+///
+/// A composed object listed with its own context, as in
+/// `composite_object!(Query<Context = AppContext>(UserQueries as UserContext, TaskQueries))`, is
+/// resolved with the context `juniper::FromContext` converts the composite's into, which its
+/// `from_context` constructor gets as well. Only composite objects can convert the context of
+/// their members.
+///
+/// Fields are resolved by calling the resolvers of the composed objects directly, so errors they
+/// return, extensions included, reach the response unchanged. Their path is made of the names the
+/// fields are exposed under, like for any juniper object:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, FieldError, FieldResult, RootNode, Variables,
+///     graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct TaskQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl TaskQueries {
+///     fn task(id: i32) -> FieldResult<Option<String>> {
+///         Err(FieldError::new(
+///             "Task not found",
+///             graphql_value!({ "code": "NOT_FOUND", "id": id }),
+///         ))
+///     }
+/// }
+///
+/// composite_object!(Query(UserQueries, prefix admin TaskQueries));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let query = "{ userCount adminTask(id: 7) }";
+/// let (data, errors) =
+///     juniper::execute_sync(query, None, &schema, &Variables::new(), &()).unwrap();
+/// assert_eq!(data, graphql_value!({ "userCount": 2, "adminTask": null }));
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].error().message(), "Task not found");
+/// assert_eq!(
+///     errors[0].error().extensions(),
+///     &graphql_value!({ "code": "NOT_FOUND", "id": 7 }),
+/// );
+/// assert_eq!(errors[0].path(), ["adminTask"]);
+/// ```
+///
+/// The executor is passed on as well, so `executor.look_ahead()` in a composed resolver is rooted
+/// at the field being resolved, and its `field_original_name()` is the name the field is exposed
+/// under:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     DefaultScalarValue, EmptyMutation, EmptySubscription, Executor, GraphQLObject, RootNode,
+///     Variables, graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(GraphQLObject)]
+/// struct User {
+///     name: String,
+///     email: String,
+/// }
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object(scalar = DefaultScalarValue)]
+/// impl UserQueries {
+///     /// Names the user after the field it's selected by and the fields selected of it.
+///     fn viewer(executor: &Executor<'_, '_, (), DefaultScalarValue>) -> User {
+///         let selection = executor.look_ahead();
+///         let children = selection.children();
+///         let children = children.iter().map(|child| child.field_original_name());
+///         User {
+///             name: format!(
+///                 "{}: {}",
+///                 selection.field_original_name(),
+///                 children.collect::<Vec<_>>().join(", "),
+///             ),
+///             email: "ada@example.com".into(),
+///         }
+///     }
+/// }
+///
+/// composite_object!(Query(prefix my UserQueries));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let (data, errors) =
+///     juniper::execute_sync("{ myViewer { name email } }", None, &schema, &Variables::new(), &())
+///         .unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(
+///     data,
+///     graphql_value!({
+///         "myViewer": { "name": "myViewer: name, email", "email": "ada@example.com" },
+///     }),
+/// );
+/// ```
+///
+/// A field no composed object exposes is an error like on any juniper object, never a panic.
+/// Juniper rejects queries selecting it before executing them, and the composite resolves it, when
+/// asked to directly, to the error of [unknown_field](unknown_field), which is the one juniper's
+/// object macros return. Below, `Root` is written by hand to declare a field its composite
+/// doesn't expose, so that queries selecting it pass validation and reach the composite:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     Arguments, DefaultScalarValue, EmptyMutation, EmptySubscription, ExecutionResult,
+///     Executor, GraphQLError, GraphQLType, GraphQLValue, Registry, RootNode, Variables,
+///     graphql_object, graphql_value, meta::MetaType,
+/// };
+/// use juniper_compose::{composable_object, composite_object, member_fields};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct TaskQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl TaskQueries {
+///     fn task_count() -> i32 {
+///         3
+///     }
+/// }
+///
+/// composite_object!(Query(UserQueries));
+///
+/// // Declares the fields of both members, but resolves every field with `Query`.
+/// struct Root;
+///
+/// impl GraphQLType for Root {
+///     fn name(_: &()) -> Option<&str> {
+///         Some("Root")
+///     }
+///
+///     fn meta<'r>(info: &(), registry: &mut Registry<'r>) -> MetaType<'r>
+///     where
+///         DefaultScalarValue: 'r,
+///     {
+///         let mut fields = member_fields::<UserQueries, _>(info, registry);
+///         fields.extend(member_fields::<TaskQueries, _>(info, registry));
+///         registry.build_object_type::<Self>(info, &fields).into_meta()
+///     }
+/// }
+///
+/// impl GraphQLValue for Root {
+///     type Context = ();
+///     type TypeInfo = ();
+///
+///     fn type_name<'i>(&self, info: &'i ()) -> Option<&'i str> {
+///         Self::name(info)
+///     }
+///
+///     fn resolve_field(
+///         &self,
+///         info: &(),
+///         field_name: &str,
+///         arguments: &Arguments,
+///         executor: &Executor<()>,
+///     ) -> ExecutionResult {
+///         Query.resolve_field(info, field_name, arguments, executor)
+///     }
+/// }
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let result = juniper::execute_sync("{ taskCount }", None, &schema, &Variables::new(), &());
+/// let Err(GraphQLError::ValidationError(errors)) = result else {
+///     panic!("expected a validation error, got {result:?}");
+/// };
+/// assert_eq!(errors[0].message(), r#"Unknown field "taskCount" on type "Query""#);
+///
+/// let schema = RootNode::new(Root, EmptyMutation::new(), EmptySubscription::new());
+/// let query = "{ userCount taskCount }";
+/// let (data, errors) =
+///     juniper::execute_sync(query, None, &schema, &Variables::new(), &()).unwrap();
+/// assert_eq!(data, graphql_value!(null));
+/// assert_eq!(errors[0].error().message(), "Field `taskCount` not found on type `Query`");
+/// ```
+///
+/// Each field is resolved by its own future, which holds no lock and borrows nothing mutably, so
+/// juniper's asynchronous execution resolves the fields of a query selection set concurrently,
+/// whichever composed objects define them:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+/// use std::{
+///     future::{Future, poll_fn},
+///     pin::pin,
+///     sync::atomic::{AtomicUsize, Ordering},
+///     task::{self, Poll, Waker},
+/// };
+///
+/// pub struct Context {
+///     waiting: AtomicUsize,
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// impl Context {
+///     /// Waits until both resolvers are waiting, which only happens if they are polled
+///     /// concurrently.
+///     async fn meet(&self) {
+///         self.waiting.fetch_add(1, Ordering::SeqCst);
+///         poll_fn(|task| {
+///             if self.waiting.load(Ordering::SeqCst) == 2 {
+///                 Poll::Ready(())
+///             } else {
+///                 task.waker().wake_by_ref();
+///                 Poll::Pending
+///             }
+///         })
+///         .await;
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object(context = Context)]
+/// impl UserQueries {
+///     async fn user_count(context: &Context) -> i32 {
+///         context.meet().await;
+///         2
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct TaskQueries;
+///
+/// #[composable_object]
+/// #[graphql_object(context = Context)]
+/// impl TaskQueries {
+///     async fn task_count(context: &Context) -> i32 {
+///         context.meet().await;
+///         3
+///     }
+/// }
+///
+/// composite_object!(Query<Context = Context>(UserQueries, TaskQueries));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let (context, variables) = (Context { waiting: AtomicUsize::new(0) }, Variables::new());
+/// let query = "{ userCount taskCount }";
+/// let mut future = pin!(juniper::execute(query, None, &schema, &variables, &context));
+/// let mut task = task::Context::from_waker(Waker::noop());
+/// // Resolved one after the other, the first resolver would wait forever.
+/// let result = (0..10)
+///     .find_map(|_| match future.as_mut().poll(&mut task) {
+///         Poll::Ready(result) => Some(result),
+///         Poll::Pending => None,
+///     })
+///     .expect("the resolvers are not polled concurrently");
+/// let (data, errors) = result.unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(data, graphql_value!({ "userCount": 2, "taskCount": 3 }));
+/// ```
+///
+/// Juniper flattens named and inline fragments into the selection set before resolving its
+/// fields one at a time, so a fragment can select fields of any number of composed objects, as
+/// the composite's fields come from a single type:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct TaskQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl TaskQueries {
+///     fn task_count() -> i32 {
+///         3
+///     }
+/// }
+///
+/// composite_object!(Query(UserQueries, TaskQueries));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let query = r#"
+///     {
+///         ...Counts
+///         ... on Query {
+///             users: userCount
+///             tasks: taskCount
+///         }
+///     }
+///
+///     fragment Counts on Query {
+///         taskCount
+///         userCount
+///     }
+/// "#;
+/// let (data, errors) =
+///     juniper::execute_sync(query, None, &schema, &Variables::new(), &()).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(
+///     data,
+///     graphql_value!({ "taskCount": 3, "userCount": 2, "users": 2, "tasks": 3 }),
+/// );
+/// ```
+///
+/// These futures borrow the context for the whole execution, so asynchronous resolvers can hold
+/// references into it across `.await`s, including into the converted context of a composed object
+/// listed with its own context:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, FromContext, RootNode, Variables, graphql_object,
+///     graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+/// use std::{
+///     future::{Future, poll_fn},
+///     pin::pin,
+///     task::{Context, Poll, Waker},
+/// };
+///
+/// async fn yield_now() {
+///     let mut yielded = false;
+///     poll_fn(|task| {
+///         if yielded {
+///             Poll::Ready(())
+///         } else {
+///             yielded = true;
+///             task.waker().wake_by_ref();
+///             Poll::Pending
+///         }
+///     })
+///     .await;
+/// }
+///
+/// struct AppContext {
+///     users: UserContext,
+/// }
+///
+/// impl juniper::Context for AppContext {}
+///
+/// struct UserContext {
+///     names: Vec<String>,
+/// }
+///
+/// impl juniper::Context for UserContext {}
+///
+/// impl FromContext<AppContext> for UserContext {
+///     fn from(context: &AppContext) -> &Self {
+///         &context.users
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object(context = UserContext)]
+/// impl UserQueries {
+///     async fn first_user(context: &UserContext) -> Option<String> {
+///         let name: &str = context.names.first()?;
+///         yield_now().await;
+///         Some(name.to_owned())
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct CountQueries;
+///
+/// #[composable_object]
+/// #[graphql_object(context = AppContext)]
+/// impl CountQueries {
+///     async fn user_count(context: &AppContext) -> i32 {
+///         let names: &[String] = &context.users.names;
+///         yield_now().await;
+///         names.len().try_into().unwrap()
+///     }
+/// }
+///
+/// composite_object!(Query<Context = AppContext>(UserQueries as UserContext, CountQueries));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let context = AppContext {
+///     users: UserContext {
+///         names: vec!["Ann".into(), "Bob".into()],
+///     },
+/// };
+/// let variables = Variables::new();
+/// let query = "{ firstUser userCount }";
+/// let mut execution = pin!(juniper::execute(query, None, &schema, &variables, &context));
+/// let mut task = Context::from_waker(Waker::noop());
+/// let (data, errors) = loop {
+///     if let Poll::Ready(result) = execution.as_mut().poll(&mut task) {
+///         break result.unwrap();
+///     }
+/// };
+/// assert!(errors.is_empty());
+/// assert_eq!(data, graphql_value!({ "firstUser": "Ann", "userCount": 2 }));
+/// ```
+///
+/// Fields of a composed object can be renamed with a `{ field: new_name }` block after its type.
+/// Identifiers are converted to camelCase like juniper does for resolvers, string literals are
+/// used verbatim. Renaming a field the object doesn't define is a compile error.
+///
+/// A field can be exposed under several names with `{ field: (old_name, new_name) }`: every name
+/// but the last is a deprecated alias resolved by the same resolver, e.g. to keep an old name
+/// working while clients migrate to the new one. Aliases are deprecated with the reason
+/// "Renamed to `newName`.", or the one given after them: `(old_name = "Use newName.", new_name)`.
+/// An alias of a field that is deprecated itself keeps the deprecation reason of the field.
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// composite_object!(Query(UserQueries {
+///     user_count: (total_users, users = "Use userCount.", user_count),
+/// }));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let query = r#"{
+///     totalUsers
+///     __type(name: "Query") {
+///         fields(includeDeprecated: true) { name isDeprecated deprecationReason }
+///     }
+/// }"#;
+/// let (data, errors) =
+///     juniper::execute_sync(query, None, &schema, &Variables::new(), &()).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(
+///     data,
+///     graphql_value!({
+///         "totalUsers": 2,
+///         "__type": {
+///             "fields": [
+///                 { "name": "userCount", "isDeprecated": false, "deprecationReason": null },
+///                 {
+///                     "name": "totalUsers",
+///                     "isDeprecated": true,
+///                     "deprecationReason": "Renamed to `userCount`.",
+///                 },
+///                 {
+///                     "name": "users",
+///                     "isDeprecated": true,
+///                     "deprecationReason": "Use userCount.",
+///                 },
+///             ],
+///         },
+///     }),
+/// );
+/// ```
+///
+/// All fields of a composed object except the renamed ones can be prefixed with
+/// `prefix some_prefix` before its type: `dashboard` becomes `somePrefixDashboard`. An identifier
+/// prefix is converted to camelCase, a string literal prefix is used verbatim.
+///
+/// Fields of a composed object can be left out with `- (field, other_field)` after its type (and
+/// renames). They are not part of the schema, so querying them fails like querying any unknown
+/// field. Excluding a field the object doesn't define is a compile error.
+///
+/// Conversely, `only (field, other_field)` after the type of a composed object exposes only the
+/// listed fields, which it must define. A composed object can't have both.
+///
+/// One composed object can be listed as `fallback`, as in
+/// `composite_object!(Query(UserQueries, fallback DynamicQueries))`. It is exempt from the
+/// collision check: its fields that another composed object exposes as well are left out, so the
+/// other one resolves them. The fallback resolves the rest of its fields, along with any field the
+/// composite is asked to resolve that no composed object exposes, under the name it is asked for.
+/// Combined with [`DYNAMIC_FIELDS`](ComposableObject::DYNAMIC_FIELDS), this lets a fallback
+/// expose fields registered at run time.
+///
+/// At least one object must be composed, since a GraphQL object type has at least one field:
+///
+/// ```compile_fail
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper_compose::composite_object;
+///
+/// // error: a composite type requires at least one member
+/// composite_object!(Query());
+/// ```
+///
+/// A composite object of a single object has exactly the fields of that object. Composed objects
+/// without fields, such as placeholders implementing [ComposableObject](ComposableObject) by hand
+/// with empty [FIELDS](ComposableObject::FIELDS), contribute nothing, but a composite object whose
+/// members expose no fields at all fails to compile:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     Arguments, DefaultScalarValue, EmptyMutation, EmptySubscription, ExecutionResult, Executor,
+///     GraphQLType, GraphQLValue, GraphQLValueAsync, Registry, RootNode, Variables,
+///     graphql_object, graphql_value, meta::MetaType,
+/// };
+/// use juniper_compose::{ComposableObject, composable_object, composite_object};
+///
+/// /// A placeholder for queries to come.
+/// #[derive(Default)]
+/// struct Placeholder;
+///
+/// impl GraphQLType for Placeholder {
+///     fn name(_: &()) -> Option<&str> {
+///         Some("Placeholder")
+///     }
+///
+///     fn meta<'r>(info: &(), registry: &mut Registry<'r>) -> MetaType<'r>
+///     where
+///         DefaultScalarValue: 'r,
+///     {
+///         registry.build_object_type::<Self>(info, &[]).into_meta()
+///     }
+/// }
+///
+/// impl GraphQLValue for Placeholder {
+///     type Context = ();
+///     type TypeInfo = ();
+///
+///     fn type_name<'i>(&self, info: &'i ()) -> Option<&'i str> {
+///         Self::name(info)
+///     }
+///
+///     fn resolve_field(
+///         &self,
+///         _: &(),
+///         _: &str,
+///         _: &Arguments,
+///         _: &Executor<()>,
+///     ) -> ExecutionResult {
+///         unreachable!("a placeholder has no fields")
+///     }
+/// }
+///
+/// impl GraphQLValueAsync for Placeholder {}
+///
+/// impl ComposableObject for Placeholder {
+///     const TYPE_NAME: &'static str = "Placeholder";
+///     const FIELDS: &'static [&'static str] = &[];
+/// }
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// composite_object!(Query(Placeholder, UserQueries));
+///
+/// assert_eq!(<Query as ComposableObject>::FIELDS, ["userCount"]);
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let variables = Variables::new();
+/// let query = r#"{ userCount __type(name: "Query") { fields { name } } }"#;
+/// let (data, errors) = juniper::execute_sync(query, None, &schema, &variables, &()).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(
+///     data,
+///     graphql_value!({
+///         "userCount": 2,
+///         "__type": { "fields": [{ "name": "userCount" }] },
+///     }),
+/// );
+/// ```
+///
+/// ```compile_fail
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// # use juniper::{
+/// #     Arguments, DefaultScalarValue, ExecutionResult, Executor, GraphQLType, GraphQLValue,
+/// #     GraphQLValueAsync, Registry, meta::MetaType,
+/// # };
+/// # use juniper_compose::{ComposableObject, composite_object};
+/// #
+/// # /// A placeholder for queries to come.
+/// # #[derive(Default)]
+/// # struct Placeholder;
+/// #
+/// # impl GraphQLType for Placeholder {
+/// #     fn name(_: &()) -> Option<&str> {
+/// #         Some("Placeholder")
+/// #     }
+/// #
+/// #     fn meta<'r>(info: &(), registry: &mut Registry<'r>) -> MetaType<'r>
+/// #     where
+/// #         DefaultScalarValue: 'r,
+/// #     {
+/// #         registry.build_object_type::<Self>(info, &[]).into_meta()
+/// #     }
+/// # }
+/// #
+/// # impl GraphQLValue for Placeholder {
+/// #     type Context = ();
+/// #     type TypeInfo = ();
+/// #
+/// #     fn type_name<'i>(&self, info: &'i ()) -> Option<&'i str> {
+/// #         Self::name(info)
+/// #     }
+/// #
+/// #     fn resolve_field(
+/// #         &self,
+/// #         _: &(),
+/// #         _: &str,
+/// #         _: &Arguments,
+/// #         _: &Executor<()>,
+/// #     ) -> ExecutionResult {
+/// #         unreachable!("a placeholder has no fields")
+/// #     }
+/// # }
+/// #
+/// # impl GraphQLValueAsync for Placeholder {}
+/// #
+/// # impl ComposableObject for Placeholder {
+/// #     const TYPE_NAME: &'static str = "Placeholder";
+/// #     const FIELDS: &'static [&'static str] = &[];
+/// # }
+/// // error: composite `Query` has no fields, since its members expose none, but a GraphQL type
+/// // has at least one field
+/// composite_object!(Query(Placeholder));
+/// ```
+///
+/// A handful of top-level fields, like `version` or `health`, can be composed from an object of
+/// their own, as in `composite_object!(Query(Misc, UserQueries))`, or defined inline by resolvers
+/// in braces after the members:
+/// `composite_object!(Query(UserQueries) { fn version() -> &'static str { "1.0" } })`. The
+/// resolvers are those of a `#[graphql_object]` impl with the context and scalar value type of the
+/// composite object, on a generated `QueryFields` unit struct composed after the listed members,
+/// so they are checked against the other fields like theirs. Composite objects with `Info` or
+/// lifetime parameters can't have inline fields.
+///
+/// The unit struct is named after the composite object followed by `Fields`, and is defined next
+/// to it with its visibility, so that its resolvers can be called directly. That name is reserved
+/// in the module of the invocation: another type of the same name there collides with it.
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// composite_object!(Query(UserQueries) {
+///     fn version() -> &'static str {
+///         "1.0"
+///     }
+/// });
+///
+/// assert_eq!(QueryFields::version(), "1.0");
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let (data, errors) =
+///     juniper::execute_sync("{ userCount version }", None, &schema, &Variables::new(), &())
+///         .unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(data, graphql_value!({ "userCount": 2, "version": "1.0" }));
+/// ```
+///
+/// An inline field sharing its name with a field of a listed member is reported like a collision
+/// between members:
+///
+/// ```compile_fail
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::graphql_object;
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn version() -> &'static str {
+///         "0.9"
+///     }
+/// }
+///
+/// // error: field `version` is defined by both `UserQueries` and `QueryFields`
+/// composite_object!(Query(UserQueries) {
+///     fn version() -> &'static str {
+///         "1.0"
+///     }
+/// });
+/// ```
+///
+/// A composed object can be preceded by `#[cfg(...)]` attributes, as in
+/// `composite_object!(Query(UserQueries, #[cfg(feature = "admin")] AdminQueries))`, in which case
+/// it is only composed when the configuration predicate holds. Its fields are left out of the
+/// schema, the resolvers and the compile-time checks otherwise. Doc comments on composed objects
+/// are allowed and ignored, and every list in the invocation accepts a trailing comma, so long
+/// member lists can be written one member per line:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     /// Number of users.
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct TaskQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl TaskQueries {
+///     fn task_count() -> i32 {
+///         3
+///     }
+/// }
+///
+/// composite_object!(Query<Context = (),>(
+///     /// Queries about users, owned by the accounts team.
+///     UserQueries,
+///     // Queries about tasks.
+///     /// Owned by the planning team.
+///     TaskQueries,
+///     #[cfg(any())]
+///     AdminQueries,
+/// ));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let query = r#"{
+///     taskCount
+///     __type(name: "Query") { description fields { name description } }
+/// }"#;
+/// let (data, errors) =
+///     juniper::execute_sync(query, None, &schema, &Variables::new(), &()).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(
+///     data,
+///     graphql_value!({
+///         "taskCount": 3,
+///         "__type": {
+///             "description": null,
+///             "fields": [
+///                 { "name": "userCount", "description": "Number of users." },
+///                 { "name": "taskCount", "description": null },
+///             ],
+///         },
+///     }),
+/// );
+/// ```
+///
+/// Composed objects are constructed with [Default](Default), or with
+/// [ComposableFromContext](ComposableFromContext) if listed as `from_context`.
+///
+/// Their fields may return the composite object itself, or a composite containing it, as in
+/// `fn parent() -> Query { Query }`: juniper registers the composite object before building the
+/// fields it refers to, so self-referential schemas are built like any other. Such objects are
+/// declared with the scalar value type of the composite object, as in
+/// `#[graphql_object(scalar = DefaultScalarValue)]`, since juniper otherwise implements them for
+/// every scalar value type and the composite only implements its own:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     DefaultScalarValue, EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object,
+///     graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct NavigationQueries;
+///
+/// #[composable_object]
+/// #[graphql_object(scalar = DefaultScalarValue)]
+/// impl NavigationQueries {
+///     fn parent() -> Query {
+///         Query
+///     }
+/// }
+///
+/// composite_object!(Query(UserQueries, NavigationQueries));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let variables = Variables::new();
+/// let query = "{ userCount parent { parent { userCount } } }";
+/// let (data, errors) = juniper::execute_sync(query, None, &schema, &variables, &()).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(
+///     data,
+///     graphql_value!({ "userCount": 2, "parent": { "parent": { "userCount": 2 } } }),
+/// );
+///
+/// let query = r#"{ __type(name: "Query") { fields { name type { kind ofType { name } } } } }"#;
+/// let (data, errors) = juniper::execute_sync(query, None, &schema, &variables, &()).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(
+///     data,
+///     graphql_value!({ "__type": { "fields": [
+///         { "name": "userCount", "type": { "kind": "NON_NULL", "ofType": { "name": "Int" } } },
+///         { "name": "parent", "type": { "kind": "NON_NULL", "ofType": { "name": "Query" } } },
+///     ] } }),
+/// );
+/// ```
+///
+/// The generated type has a `members` function returning the composed objects constructed with
+/// [Default](Default) as a tuple, in the order they are listed, so that their resolvers can be
+/// unit-tested without executing a query: `let (users, tasks) = Query::members();`. Composed
+/// objects listed as `from_context` or with `cfg` attributes are left out of it.
+///
+/// The generated type implements [Debug](std::fmt::Debug) as a struct named after its GraphQL
+/// type, listing the fields it exposes in the order of its [fields](ComposableObject::fields), so
+/// that test output and logs show what was composed. Deriving `Debug` on the invocation, as in
+/// `composite_object!(#[derive(Debug)] Query(A, B))`, keeps the derived implementation instead.
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::graphql_object;
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         0
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct TaskQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl TaskQueries {
+///     fn task_count() -> i32 {
+///         0
+///     }
+/// }
+///
+/// composite_object!(AppQuery as "Query"(UserQueries, prefix admin TaskQueries));
+///
+/// assert_eq!(
+///     format!("{AppQuery:?}"),
+///     r#"Query { fields: ["userCount", "adminTaskCount"] }"#,
+/// );
+/// ```
+///
+/// With the `test_helpers` feature, which is meant to be enabled in `[dev-dependencies]` only,
+/// the generated type also has a `build_schema` function returning a `juniper::RootNode` of it
+/// constructed with [Default](Default), with an empty mutation and subscription of its context
+/// and its scalar value type: `let schema = Query::build_schema();`. It takes the type info of the
+/// composite object as an argument if it has `Info` without `info = expr`, and its scalar value
+/// type as a type parameter if it is generic over it, as in
+/// `Query::build_schema::<DefaultScalarValue>()`.
+///
+/// With the `inventory` feature, the invocation registers the composite object in the
+/// `registry` module, with the names of its listed members.
+///
+/// The fields of the composite object appear in the order the composed objects are listed, and
+/// within each composed object in the order of its [fields](ComposableObject::fields), which is
+/// the order they are declared in. A composite object declared `sorted`, as in
+/// `composite_object!(Query sorted (A, B))`, registers them sorted by name instead, so that they
+/// appear in alphabetical order in introspection and in the SDL. The order is only that of the
+/// meta type: fields are resolved by the same members either way, and a composite it is composed
+/// into registers them in its own order. Either order is the same every time the schema is built:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, RootNode, Value, Variables, graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn users() -> i32 {
+///         2
+///     }
+///
+///     fn admins() -> i32 {
+///         1
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct TaskQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl TaskQueries {
+///     fn tasks() -> i32 {
+///         3
+///     }
+///
+///     fn archived_tasks() -> i32 {
+///         0
+///     }
+/// }
+///
+/// composite_object!(Query(TaskQueries, UserQueries));
+///
+/// fn field_names() -> Value {
+///     let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+///     let query = r#"{ __type(name: "Query") { fields { name } } }"#;
+///     let (data, errors) =
+///         juniper::execute_sync(query, None, &schema, &Variables::new(), &()).unwrap();
+///     assert!(errors.is_empty());
+///     data
+/// }
+///
+/// let names = field_names();
+/// assert_eq!(
+///     names,
+///     graphql_value!({
+///         "__type": {
+///             "fields": [
+///                 { "name": "tasks" },
+///                 { "name": "archivedTasks" },
+///                 { "name": "users" },
+///                 { "name": "admins" },
+///             ],
+///         },
+///     }),
+/// );
+/// // Once from the fields merged above, once merging them again on another thread.
+/// assert_eq!(field_names(), names);
+/// assert_eq!(std::thread::spawn(field_names).join().unwrap(), names);
+///
+/// composite_object!(SortedQuery sorted (TaskQueries, UserQueries));
+///
+/// let schema = RootNode::new(SortedQuery, EmptyMutation::new(), EmptySubscription::new());
+/// let variables = Variables::new();
+/// let query = r#"{ users __type(name: "SortedQuery") { fields { name } } }"#;
+/// let (data, errors) = juniper::execute_sync(query, None, &schema, &variables, &()).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(
+///     data,
+///     graphql_value!({
+///         "users": 2,
+///         "__type": {
+///             "fields": [
+///                 { "name": "admins" },
+///                 { "name": "archivedTasks" },
+///                 { "name": "tasks" },
+///                 { "name": "users" },
+///             ],
+///         },
+///     }),
+/// );
+/// ```
+///
+/// The declaration order is the default and `sorted` replaces it, so a composite object has a
+/// single order: `sorted` can be given once.
+///
+/// ```compile_fail
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::graphql_object;
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn users() -> i32 {
+///         2
+///     }
+/// }
+///
+/// // error: duplicate `sorted`
+/// composite_object!(Query sorted sorted (UserQueries));
+/// ```
+///
+/// `rename_all = "camelCase"`, `"snake_case"` or `"none"` before the composed objects, as in
+/// `composite_object!(Query rename_all = "snake_case" (A, B))`, converts every exposed field name
+/// to that case once renames and prefixes are applied, both in the schema and when resolving.
+/// `"none"`, like no `rename_all`, leaves the names as they are, and also asserts that the
+/// composed objects don't convert them themselves: a field named by the `rename_all` policy of
+/// its object, such as juniper's default camelCase conversion, fails to compile unless the
+/// composite names it explicitly in a `{ ... }` block. Inline fields keep their Rust names.
+///
+/// ```compile_fail
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::graphql_object;
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object(rename_all = "none")]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         0
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct TaskQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl TaskQueries {
+///     fn task_count() -> i32 {
+///         0
+///     }
+/// }
+///
+/// // error: field `taskCount` of `TaskQueries` is named by its `rename_all` policy, but
+/// // composite object `Query` is declared `rename_all = "none"`
+/// composite_object!(Query rename_all = "none" (UserQueries, TaskQueries));
+/// ```
+///
+/// `map_err = path` before the composed objects, as in
+/// `composite_object!(Query map_err = translate (A, B))`, maps the error of every field resolved
+/// by a composed object with `path`, a function from [`FieldError`](juniper::FieldError) to
+/// [`FieldError`](juniper::FieldError) such as `fn translate<S: ScalarValue>(FieldError<S>) ->
+/// FieldError<S>`, in both synchronous and asynchronous execution. Fields that resolve
+/// successfully are left untouched, and so is the error of a field the composite doesn't expose.
+///
+/// A composite object declared `sync`, as in `composite_object!(Query sync (A, B))`, fails to
+/// compile if any composed object has an `async` field, so that it can safely be executed with
+/// juniper's synchronous execution.
+///
+/// Composed objects may be any type, including instances of generic types such as
+/// `CrudQueries<User>`. Instances of the same generic type usually expose the same fields, so
+/// they need to be renamed or prefixed.
+///
+/// Lifetime parameters can be declared before the other parameters, as in
+/// `composite_object!(Query<'a, Context = Context<'a>>(from_context ReportQueries<'a>))`, and used
+/// by the context and the composed objects. Composed objects that borrow data can't be
+/// constructed with [Default](Default), so they are listed as `from_context` and borrow it from
+/// the context, whose lifetime they share. The generated type is then constructed with
+/// `Query::default()`.
+///
+/// The GraphQL name of the composite object is its identifier, unless given with `as "Name"` after
+/// the identifier. It must be a valid GraphQL name not starting with `__`.
+/// `__typename` resolves to it: juniper answers it before dispatching to the composed objects.
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     DefaultScalarValue, EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object,
+///     graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// composite_object!(AdminQuery(UserQueries));
+///
+/// #[derive(Default)]
+/// struct RootQueries;
+///
+/// #[composable_object]
+/// #[graphql_object(scalar = DefaultScalarValue)]
+/// impl RootQueries {
+///     fn admin() -> AdminQuery {
+///         AdminQuery
+///     }
+/// }
+///
+/// composite_object!(Query as "RootQuery" (RootQueries));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let query = "{ __typename admin { __typename userCount } }";
+/// let (data, errors) =
+///     juniper::execute_sync(query, None, &schema, &Variables::new(), &()).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(
+///     data,
+///     graphql_value!({
+///         "__typename": "RootQuery",
+///         "admin": { "__typename": "AdminQuery", "userCount": 2 },
+///     }),
+/// );
+/// ```
+///
+/// Doc comments on the invocation document the generated type and become the description of the
+/// GraphQL type, unless it is given with `#[graphql(description = "...")]`.
+/// Other attributes on the invocation, e.g. `#[derive(Clone)]` or
+/// `#[cfg_attr(test, derive(Debug))]`, are applied to the generated type, which derives `Default`
+/// unless they do. `#[cfg(...)]` goes on the invocation itself, so that it applies to the impls
+/// of the generated type as well.
+///
+/// The generated type implements [ComposableObject](ComposableObject) itself, with the fields
+/// it exposes, so it can be composed into another composite object.
+///
+/// It is a GraphQL object type of its own as well, which fields can return like any other: as a
+/// non-null field, or a nullable one when wrapped in `Option`. The object returning it must be
+/// resolved with its scalar value type, which `#[graphql_object]` otherwise leaves generic:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     DefaultScalarValue, EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object,
+///     graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// composite_object!(AdminQuery(UserQueries));
+///
+/// #[derive(Default)]
+/// struct RootQueries;
+///
+/// #[composable_object]
+/// #[graphql_object(scalar = DefaultScalarValue)]
+/// impl RootQueries {
+///     fn admin() -> AdminQuery {
+///         AdminQuery
+///     }
+///
+///     fn maybe_admin() -> Option<AdminQuery> {
+///         None
+///     }
+/// }
+///
+/// composite_object!(Query(RootQueries));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let query = r#"{
+///     admin { userCount }
+///     maybeAdmin { userCount }
+///     __type(name: "Query") { fields { type { kind ofType { name } } } }
+/// }"#;
+/// let (value, _) = juniper::execute_sync(query, None, &schema, &Variables::new(), &()).unwrap();
+/// assert_eq!(
+///     value,
+///     graphql_value!({
+///         "admin": { "userCount": 2 },
+///         "maybeAdmin": null,
+///         "__type": { "fields": [
+///             { "type": { "kind": "NON_NULL", "ofType": { "name": "AdminQuery" } } },
+///             { "type": { "kind": "OBJECT", "ofType": null } },
+///         ] },
+///     }),
+/// );
+/// ```
+///
+/// The generated type also has a `field_origin` function. It takes the name of a field and returns
+/// the name of the composed object that resolves it, e.g. `Query::field_origin("taskCount")`
+/// returns `Some("TaskQueries")`. For a nested composite object, that is the name of the nested
+/// composite object. `Query::member_type_names()` lists the names of the composed objects in the
+/// order they are listed, e.g. `["UserQueries", "TaskQueries"]`, leaving out the ones a `cfg`
+/// attribute excludes.
+///
+/// `Query::FIELD_COUNT` is the number of fields the generated type exposes, e.g. to catch fields
+/// being added or removed with `const _: () = assert!(Query::FIELD_COUNT == 12);`. Composite
+/// subscriptions, interfaces and input objects define it as well.
+///
+/// A `where` clause after the composed objects, as in
+/// `composite_object!(Query<Context = Context>(A, B) where Context: HasDb + Sync)`, bounds every
+/// impl of the generated type, so that an unsatisfied bound is reported once, at the clause.
+///
+/// A composite object declared `federation`, as in `composite_object!(Query federation (A, B))`,
+/// also exposes the `_service { sdl }` field Apollo Federation requires of the query type of a
+/// subgraph, see [Federation](Federation). `_entities` depends on how entities are fetched, so it
+/// is left to a composed object of the application.
+///
+/// Members listed as `resolve_reference`, as in
+/// `composite_object!(Query federation (resolve_reference UserQueries, TaskQueries))`, resolve
+/// the entities of the subgraph with [ResolveReference](ResolveReference). The composite then
+/// implements [ResolveEntities](ResolveEntities), trying them in the order they are listed, so
+/// that the `_entities` resolver of the application doesn't need to know which module owns which
+/// entity type. Listing them anywhere but in a composite object declared `federation` is an
+/// error.
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{GraphQLObject, GraphQLUnion, InputValue, graphql_object};
+/// use juniper_compose::{
+///     Representation, ResolveEntities, ResolveReference, composable_object, composite_object,
+/// };
+///
+/// #[derive(GraphQLObject)]
+/// struct User {
+///     id: String,
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// struct Task {
+///     id: String,
+/// }
+///
+/// #[derive(GraphQLUnion)]
+/// enum Entity {
+///     User(User),
+///     Task(Task),
+/// }
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn viewer() -> User {
+///         User { id: "1".into() }
+///     }
+/// }
+///
+/// impl ResolveReference for UserQueries {
+///     type Entity = Entity;
+///
+///     fn resolve_reference((): &(), representation: &Representation) -> Option<Entity> {
+///         let id = representation.get("id")?.as_string_value()?;
+///         (representation.typename() == "User").then(|| Entity::User(User { id: id.into() }))
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct TaskQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl TaskQueries {
+///     fn task_count() -> i32 {
+///         0
+///     }
+/// }
+///
+/// impl ResolveReference for TaskQueries {
+///     type Entity = Entity;
+///
+///     fn resolve_reference((): &(), representation: &Representation) -> Option<Entity> {
+///         let id = representation.get("id")?.as_string_value()?;
+///         (representation.typename() == "Task").then(|| Entity::Task(Task { id: id.into() }))
+///     }
+/// }
+///
+/// composite_object!(
+///     Query federation (resolve_reference UserQueries, resolve_reference TaskQueries)
+/// );
+///
+/// let representation = |typename: &str| {
+///     Representation::try_from(InputValue::object(
+///         [("__typename", InputValue::scalar(typename)), ("id", InputValue::scalar("7"))]
+///             .into_iter()
+///             .collect(),
+///     ))
+///     .unwrap()
+/// };
+/// let entities = Query::resolve_entities(
+///     &(),
+///     &[representation("Task"), representation("Review"), representation("User")],
+/// );
+/// assert!(matches!(&entities[0], Some(Entity::Task(task)) if task.id == "7"));
+/// assert!(entities[1].is_none());
+/// assert!(matches!(&entities[2], Some(Entity::User(user)) if user.id == "7"));
+/// ```
+///
+/// `rename_all` converts the names of the fields of the composed objects only, so `_service`
+/// keeps the name Apollo Federation gives it:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object};
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object(rename_all = "none")]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// composite_object!(Query federation rename_all = "camelCase" (UserQueries));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let query = "{ userCount _service { sdl } }";
+/// let (data, errors) =
+///     juniper::execute_sync(query, None, &schema, &Variables::new(), &()).unwrap();
+/// assert!(errors.is_empty());
+/// let data = data.as_object_value().unwrap();
+/// assert_eq!(data.get_field_value("userCount"), Some(&juniper::Value::scalar(2)));
+/// let service = data.get_field_value("_service").unwrap().as_object_value().unwrap();
+/// assert!(service.get_field_value("sdl").unwrap().as_string_value().is_some());
+/// ```
+///
+/// A composite object declared `impl (...)`, as in
+/// `composite_object!(UserNode impl (Node) (IdFields, UserFields))`, implements the listed
+/// [composite_interface](composite_interface)s, which must list it in `for = (...)` in turn. The
+/// merged fields are checked at compile time to include every field of each interface, with a
+/// type the field of the interface can be implemented by, as juniper checks the fields of its own
+/// interfaces: the same type or one of its implementers, non-null where the interface allows
+/// null. Types are only compared when every member lists them in its `FIELD_TYPES`, which
+/// [composable_object](composable_object) and [composable_interface](composable_interface) do.
+///
+/// ```compile_fail
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{graphql_interface, graphql_object};
+/// use juniper_compose::{
+///     composable_interface, composable_object, composite_interface, composite_object,
+/// };
+///
+/// #[composable_interface]
+/// #[graphql_interface]
+/// trait Identifiable {
+///     fn id(&self) -> String;
+/// }
+///
+/// #[derive(Default)]
+/// struct IdFields;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl IdFields {
+///     fn id() -> i32 {
+///         1
+///     }
+/// }
+///
+/// composite_interface!(Node(IdentifiableValue) for = (UserNode));
+/// // error: field `id` of interface `Node` has type `String!`, but `UserNode` defines it with
+/// // type `Int!`
+/// composite_object!(UserNode impl (Node) (IdFields));
+/// ```
+///
+/// ## Examples
+///
+/// ```ignore
+/// use juniper_compose_ng::composite_object;
+///
+/// #[derive(Default)] struct UserQueries;
+/// #[derive(Default)] struct TaskQueries;
+/// #[derive(Default)] struct UserMutations;
+/// #[derive(Default)] struct TaskMutations;
+/// struct MyContextType;
+/// // `MyScalarValue` implements `juniper::ScalarValue`
+///
+/// composite_object!(Query(UserQueries, TaskQueries));
+/// composite_object!(Mutation<Context = MyContextType>(UserMutations, TaskMutations));
+/// composite_object!(QueryWithScalar<Context = MyContextType, ScalarValue = MyScalarValue>(UserQueries, TaskQueries));
+/// composite_object!(pub QueryPublic(UserQueries, TaskQueries));
+/// composite_object!(QueryRenamed(UserQueries { count: user_count }, TaskQueries { "count": "taskCount" }));
+/// composite_object!(QueryPrefixed(prefix user_ UserQueries, prefix "task" TaskQueries));
+/// composite_object!(QueryFromContext<Context = MyContextType>(from_context UserQueries, TaskQueries));
+/// composite_object!(QueryContextAs<Context = MyContextType>(UserQueries as UserContext, TaskQueries));
+/// composite_object!(QueryGeneric(prefix user CrudQueries<User>, prefix task CrudQueries<Task>));
+/// composite_object!(QueryScalarGeneric<ScalarValue = MyScalarValue>(UserQueries, ScalarQueries<_>));
+/// composite_object!(QueryAnyScalar<S: ScalarValue>(UserQueries, TaskQueries));
+/// composite_object!(QuerySync<Context = MyContextType> sync (UserQueries, TaskQueries));
+/// composite_object!(QueryInst<ScalarValue = MyScalarValue> generic_members (ScalarQueries));
+/// composite_object!(QueryGated<Context = MyContextType>(gated UserQueries, TaskQueries));
+/// composite_object!(
+///     /// Top-level queries.
+///     QueryDocumented(UserQueries, TaskQueries)
+/// );
+/// composite_object!(#[graphql(description = "Top-level queries")] QueryDescribed(UserQueries, TaskQueries));
+/// composite_object!(AppQuery as "Query"(UserQueries, TaskQueries));
+/// composite_object!(QueryExcluding(UserQueries, TaskQueries - (internal_stats, debug_info)));
+/// composite_object!(QuerySelecting(UserQueries only (user, users), TaskQueries));
+/// composite_object!(DynamicQuery<Info = SchemaInfo>(DynamicQueries, StaticQueries));
+/// composite_object!(DynamicQueryBuilt<Info = SchemaInfo>(DynamicQueries, StaticQueries) info = build_info());
+/// composite_object!(SubgraphQuery<Context = MyContextType> federation (UserQueries, TaskQueries));
+/// composite_object!(EntityQuery<Context = MyContextType> federation (resolve_reference UserQueries, TaskQueries));
+/// composite_object!(QueryBorrowing<'a, Context = BorrowingContext<'a>>(from_context ReportQueries<'a>, TaskQueries<'a>));
+/// composite_object!(QueryBounded<Context = MyContextType>(UserQueries, TaskQueries) where MyContextType: HasDb + Sync);
+/// composite_object!(UserNode impl (Node) (IdFields, UserFields));
+/// ```
+pub use juniper_compose_macros_ng::composite_object;
+
+/// Implements [ComposableSubscription](ComposableSubscription) for a GraphQL subscription type.
+/// **Important**: must be applied before the `juniper::graphql_subscription` macro.
+///
+/// `#[composable_subscription(default)]` implements [Default](Default) for a subscription without
+/// fields, like [composable_object](composable_object).
+///
+/// ## Example
+///
+/// ```ignore
+/// use juniper_compose_ng::composable_subscription;
+/// use juniper::graphql_subscription;
+///
+/// #[derive(Default)] struct UserSubscriptions;
+///
+/// #[composable_subscription]
+/// #[graphql_subscription]
+/// impl UserSubscriptions {
+///     // ...
+/// }
+/// ```
+pub use juniper_compose_macros_ng::composable_subscription;
+
+/// Composes a subscription type from multiple [ComposableSubscription](ComposableSubscription)s.
+/// Accepts the same parameters as [composite_object](composite_object).
+///
+/// The streams of the members don't need to be of the same type: juniper boxes the stream of
+/// every field into the `ValuesStream` it resolves subscriptions into, so a member returning a
+/// `BoxStream` composes with one returning a concrete stream type. Fields returning `impl Stream`
+/// are rejected by `juniper::graphql_subscription` itself and need to be boxed or named:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, RootNode, Value, Variables, graphql_object, graphql_subscription,
+///     graphql_value,
+///     futures::{
+///         StreamExt,
+///         stream::{self, BoxStream},
+///     },
+/// };
+/// use juniper_compose::{
+///     composable_object, composable_subscription, composite_object, composite_subscription,
+/// };
+/// use std::{
+///     pin::pin,
+///     task::{Context, Poll, Waker},
+/// };
+///
+/// #[derive(Default)]
+/// struct UserQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl UserQueries {
+///     fn user_count() -> i32 {
+///         2
+///     }
+/// }
+///
+/// composite_object!(Query(UserQueries));
+///
+/// #[derive(Default)]
+/// struct UserSubscriptions;
+///
+/// #[composable_subscription]
+/// #[graphql_subscription]
+/// impl UserSubscriptions {
+///     async fn user_created() -> BoxStream<'static, i32> {
+///         Box::pin(stream::iter([1, 2]))
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct TaskSubscriptions;
+///
+/// #[composable_subscription]
+/// #[graphql_subscription]
+/// impl TaskSubscriptions {
+///     async fn task_renamed() -> stream::Iter<std::array::IntoIter<String, 2>> {
+///         stream::iter(["Write docs".to_owned(), "Ship".to_owned()])
+///     }
+/// }
+///
+/// composite_subscription!(Subscription(UserSubscriptions, TaskSubscriptions));
+///
+/// // The resolvers never suspend, so the futures complete without being woken.
+/// fn block_on<F: Future>(future: F) -> F::Output {
+///     let mut future = pin!(future);
+///     let mut context = Context::from_waker(Waker::noop());
+///     loop {
+///         if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+///             return output;
+///         }
+///     }
+/// }
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), Subscription);
+/// let variables = Variables::new();
+/// let values = |query| {
+///     let (value, errors) =
+///         block_on(juniper::resolve_into_stream(query, None, &schema, &variables, &())).unwrap();
+///     assert!(errors.is_empty());
+///     let Value::Object(fields) = value else {
+///         panic!("a subscription resolves into an object of streams");
+///     };
+///     let (_, Value::Scalar(stream)) = fields.into_iter().next().unwrap() else {
+///         panic!("a field of a subscription resolves into a stream");
+///     };
+///     block_on(stream.map(Result::unwrap).collect::<Vec<_>>())
+/// };
+/// assert_eq!(
+///     values("subscription { userCreated }"),
+///     [graphql_value!(1), graphql_value!(2)],
+/// );
+/// assert_eq!(
+///     values("subscription { taskRenamed }"),
+///     [graphql_value!("Write docs"), graphql_value!("Ship")],
+/// );
+/// ```
+///
+/// Every member must be `Send`: it is moved into the future resolving its stream, which juniper
+/// sends between threads, as it does the stream itself. A member that isn't is reported at the
+/// point it is listed:
+///
+/// ```compile_fail
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{FieldError, futures::stream::{self, BoxStream}, graphql_subscription};
+/// use juniper_compose::{composable_subscription, composite_subscription};
+/// use std::{marker::PhantomData, sync::MutexGuard};
+///
+/// #[derive(Default)]
+/// struct TaskSubscriptions {
+///     _guard: PhantomData<MutexGuard<'static, ()>>,
+/// }
+///
+/// #[composable_subscription]
+/// #[graphql_subscription]
+/// impl TaskSubscriptions {
+///     async fn tasks() -> BoxStream<'static, Result<i32, FieldError>> {
+///         Box::pin(stream::iter([Ok(1)]))
+///     }
+/// }
+///
+/// // error: `MutexGuard<'static, ()>` cannot be sent between threads safely
+/// composite_subscription!(Subscription(TaskSubscriptions));
+/// ```
+///
+/// ## Examples
+///
+/// ```ignore
+/// use juniper_compose_ng::composite_subscription;
+///
+/// #[derive(Default)] struct UserSubscriptions;
+/// #[derive(Default)] struct TaskSubscriptions;
+/// struct MyContextType;
+///
+/// composite_subscription!(Subscription<Context = MyContextType>(UserSubscriptions, TaskSubscriptions));
+/// ```
+pub use juniper_compose_macros_ng::composite_subscription;
+
+/// Implements [ComposableInterface](ComposableInterface) for a GraphQL interface trait.
+/// **Important**: must be applied before the `juniper::graphql_interface` macro.
+///
+/// The interface is only used for its field definitions, so it usually doesn't list any
+/// implementers in `for = ...`.
+///
+/// ## Example
+///
+/// ```ignore
+/// use juniper_compose_ng::composable_interface;
+/// use juniper::graphql_interface;
+///
+/// #[composable_interface]
+/// #[graphql_interface]
+/// trait Identifiable {
+///     fn id(&self) -> &str;
+/// }
+/// ```
+pub use juniper_compose_macros_ng::composable_interface;
+
+/// Composes an interface type from multiple [ComposableInterface](ComposableInterface)s,
+/// implemented by the object types listed in `for = (...)`. Composable interfaces are listed by
+/// the enum `juniper::graphql_interface` generates for them (`{Trait}Value` by default).
+/// Accepts the same parameters as [composite_object](composite_object), but fields can't be
+/// renamed or prefixed since they are resolved by the implementers. Unless `ScalarValue` is
+/// specified, the interface is implemented for every scalar value type, like juniper interfaces.
+///
+/// The generated type is an enum with a variant holding each implementer, and can be used in
+/// `#[graphql_object(impl = ...)]` like the enum generated by `juniper::graphql_interface`.
+/// Field names are checked for collisions the same way as in
+/// [composite_object](composite_object), and every implementer is checked to define every field.
+/// A `where` clause goes after `for = (...)`.
+///
+/// Composite objects can implement the interface, see [composite_object](composite_object).
+/// They are only implemented for one scalar value type, so neither can a generic interface
+/// listing one be used with any other, and the types using it need the same concrete scalar.
+/// Interfaces of `juniper::graphql_interface` can't list composite objects.
+///
+/// ## Examples
+///
+/// ```ignore
+/// use juniper_compose_ng::composite_interface;
+/// use juniper::graphql_object;
+///
+/// struct MyContextType;
+///
+/// // `IdentifiableValue` and `TimestampedValue` are generated by `juniper::graphql_interface`
+/// composite_interface!(Node<Context = MyContextType>(IdentifiableValue, TimestampedValue) for = (User, Task));
+///
+/// #[graphql_object(context = MyContextType, impl = Node)]
+/// impl User {
+///     // ...
+/// }
+/// ```
+pub use juniper_compose_macros_ng::composite_interface;
+
+/// Implements [ComposableInputObject](ComposableInputObject) for a GraphQL input object type.
+/// **Important**: must be applied before `#[derive(juniper::GraphQLInputObject)]`.
+///
+/// ## Example
+///
+/// ```ignore
+/// use juniper_compose_ng::composable_input_object;
+/// use juniper::GraphQLInputObject;
+///
+/// #[composable_input_object]
+/// #[derive(GraphQLInputObject)]
+/// struct UserFilter {
+///     name: Option<String>,
+/// }
+/// ```
+pub use juniper_compose_macros_ng::composable_input_object;
+
+/// Composes an input object type from multiple [ComposableInputObject](ComposableInputObject)s.
+/// Accepts the same parameters as [composite_object](composite_object), but members can only be
+/// renamed or prefixed, and the composite can't be declared `sync` or `federation`. Unless
+/// `ScalarValue` is specified, the input object is implemented for every scalar value type.
+///
+/// The generated type is a tuple struct holding a value of each member, in order. Each field of
+/// an input value is passed to the member defining it, under the name the member gives it.
+/// Field names are checked for collisions the same way as in
+/// [composite_object](composite_object).
+///
+/// ## Example
+///
+/// ```ignore
+/// use juniper_compose_ng::composite_input_object;
+///
+/// composite_input_object!(TaskFilter(UserFilter { name: assignee_name }, StatusFilter));
+///
+/// #[graphql_object]
+/// impl TaskQueries {
+///     fn tasks(filter: TaskFilter) -> Vec<Task> {
+///         let TaskFilter(user_filter, status_filter) = filter;
+///         // ...
+///     }
+/// }
+/// ```
+pub use juniper_compose_macros_ng::composite_input_object;
+
+/// Implements [ComposableUnion](ComposableUnion) for a GraphQL union type.
+/// **Important**: must be applied before `#[derive(juniper::GraphQLUnion)]`.
+///
+/// Variants resolved with a custom resolver, `#[graphql(with = ...)]` on the variant or
+/// `#[graphql(on Type = ...)]` on the enum, are variants of the union like the others, and the
+/// composite union resolves them with those resolvers. Juniper's objects have no such argument:
+/// a field of a [composable_object](composable_object) calling a resolver function of its own is
+/// a method like any other:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, GraphQLObject, GraphQLUnion, RootNode, Variables,
+///     graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{
+///     ComposableUnion, composable_object, composable_union, composite_object, composite_union,
+/// };
+///
+/// #[derive(GraphQLObject)]
+/// struct Human {
+///     name: String,
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// struct Droid {
+///     model: String,
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// struct Ewok {
+///     fur: String,
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// struct Jawa {
+///     id: i32,
+/// }
+///
+/// pub struct Database {
+///     ewok: Ewok,
+/// }
+///
+/// impl juniper::Context for Database {}
+///
+/// #[composable_union]
+/// #[derive(GraphQLUnion)]
+/// #[graphql(context = Database, on Ewok = Character::ewok)]
+/// enum Character {
+///     Human(Human),
+///     #[graphql(with = Character::droid)]
+///     Droid(Droid),
+///     #[graphql(ignore)]
+///     EwokId(i32),
+/// }
+///
+/// impl Character {
+///     fn droid<'a>(&'a self, _: &Database) -> Option<&'a Droid> {
+///         match self {
+///             Self::Droid(droid) => Some(droid),
+///             _ => None,
+///         }
+///     }
+///
+///     fn ewok<'a>(&self, database: &'a Database) -> Option<&'a Ewok> {
+///         match self {
+///             Self::EwokId(_) => Some(&database.ewok),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// #[composable_union]
+/// #[derive(GraphQLUnion)]
+/// #[graphql(context = Database)]
+/// enum Settler {
+///     Jawa(Jawa),
+/// }
+///
+/// composite_union!(Being<Context = Database>(Character, Settler));
+///
+/// #[derive(Default)]
+/// struct BeingQueries;
+///
+/// #[composable_object]
+/// #[graphql_object(context = Database)]
+/// impl BeingQueries {
+///     fn beings() -> Vec<Being> {
+///         vec![
+///             Character::Human(Human { name: "Luke".to_owned() }).into(),
+///             Character::Droid(Droid { model: "R2-D2".to_owned() }).into(),
+///             Character::EwokId(1).into(),
+///             Settler::Jawa(Jawa { id: 7 }).into(),
+///         ]
+///     }
+/// }
+///
+/// composite_object!(Query<Context = Database>(BeingQueries));
+///
+/// assert_eq!(<Character as ComposableUnion>::VARIANTS, ["Human", "Droid", "Ewok"]);
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let database = Database { ewok: Ewok { fur: "brown".to_owned() } };
+/// let query = "{ beings { __typename \
+///     ... on Human { name } ... on Droid { model } ... on Ewok { fur } ... on Jawa { id } } }";
+/// let (data, errors) =
+///     juniper::execute_sync(query, None, &schema, &Variables::new(), &database).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(
+///     data,
+///     graphql_value!({ "beings": [
+///         { "__typename": "Human", "name": "Luke" },
+///         { "__typename": "Droid", "model": "R2-D2" },
+///         { "__typename": "Ewok", "fur": "brown" },
+///         { "__typename": "Jawa", "id": 7 },
+///     ] }),
+/// );
+/// ```
+///
+/// A type given a resolver with `on Type = ...` as well as held by a variant is listed once, as
+/// juniper resolves it with the resolver: types are compared as they are written, which is how
+/// juniper tells them apart too. The same type written differently, such as `Ewok` and
+/// `crate::Ewok`, is rejected by `#[derive(GraphQLUnion)]` itself with conflicting
+/// implementations for the type:
+///
+/// ```compile_fail
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{GraphQLObject, GraphQLUnion};
+/// use juniper_compose::composable_union;
+///
+/// #[derive(GraphQLObject)]
+/// pub struct Ewok {
+///     fur: String,
+/// }
+///
+/// pub struct Database;
+///
+/// impl juniper::Context for Database {}
+///
+/// #[composable_union]
+/// #[derive(GraphQLUnion)]
+/// #[graphql(context = Database, on self::Ewok = Character::ewok)]
+/// enum Character {
+///     Ewok(Ewok),
+/// }
+///
+/// impl Character {
+///     fn ewok<'a>(&'a self, _: &Database) -> Option<&'a Ewok> {
+///         let Self::Ewok(ewok) = self;
+///         Some(ewok)
+///     }
+/// }
+/// ```
+///
+/// ## Example
+///
+/// ```ignore
+/// use juniper_compose_ng::composable_union;
+/// use juniper::GraphQLUnion;
+///
+/// #[composable_union]
+/// #[derive(GraphQLUnion)]
+/// enum UserResults {
+///     User(User),
+///     Team(Team),
+/// }
+/// ```
+pub use juniper_compose_macros_ng::composable_union;
+
+/// Composes a union type from multiple [ComposableUnion](ComposableUnion)s, whose variant types
+/// are the variant types of every member. Accepts the same parameters as
+/// [composite_object](composite_object), but members can't be renamed, prefixed or listed as
+/// `from_context`, since they have no fields and are never constructed by the composite. Unless
+/// `ScalarValue` is specified, the union is implemented for every scalar value type, like juniper
+/// unions.
+///
+/// The generated type is an enum with a variant holding each member, named after the member, and
+/// a value of the union is resolved by the member it holds. If two or more members have the same
+/// variant type, `composite_union!` fails to compile with an error naming the type and the
+/// members having it.
+///
+/// ## Example
+///
+/// ```ignore
+/// use juniper_compose_ng::composite_union;
+///
+/// composite_union!(SearchResult(UserResults, TaskResults));
+///
+/// #[graphql_object]
+/// impl SearchQueries {
+///     fn search(text: String) -> Vec<SearchResult> {
+///         vec![SearchResult::UserResults(UserResults::User(user))]
+///     }
+/// }
+/// ```
+pub use juniper_compose_macros_ng::composite_union;
+
+/// Defines a schema type wrapping the `juniper::RootNode` of the given root types.
+///
+/// `query` is required. `mutation` and `subscription` default to `juniper::EmptyMutation` and
+/// `juniper::EmptySubscription` of `context`, which defaults to `()`. `scalar` is the scalar
+/// value type of the schema, `juniper::DefaultScalarValue` by default.
+///
+/// `Schema::new()` (or `Schema::default()`) builds the root node, constructing the root types
+/// with [Default](Default). The schema type dereferences to the root node, so it can be passed to
+/// `juniper::execute` as is, and `into_inner` returns the root node itself.
+///
+/// ## Example
+///
+/// ```ignore
+/// use juniper_compose_ng::{composite_object, composite_root_node};
+///
+/// composite_object!(Query<Context = Context>(UserQueries, TaskQueries));
+/// composite_object!(Mutation<Context = Context>(UserMutations, TaskMutations));
+///
+/// composite_root_node!(pub Schema { query: Query, mutation: Mutation, context: Context });
+///
+/// let schema = Schema::new();
+/// let (result, errors) = juniper::execute(query, None, &schema, &variables, &context).await?;
+/// ```
+pub use juniper_compose_macros_ng::composite_root_node;
+
+/// Asserts at compile time that the given [ComposableObject](ComposableObject)s can be composed
+/// into one object type without their field names colliding, without composing them, e.g. to check
+/// a pool of composable objects that are mixed into different schemas.
+///
+/// Objects are listed the way [composite_object](composite_object) lists them, renames and
+/// prefixes included, and are checked with
+/// [`DefaultScalarValue`](juniper::DefaultScalarValue). A collision fails to compile with the same
+/// error as in [composite_object](composite_object), naming the field and the objects defining
+/// it.
+///
+/// ## Example
+///
+/// ```ignore
+/// use juniper_compose_ng::assert_fields_disjoint;
+///
+/// assert_fields_disjoint!(UserQueries, TaskQueries { count: task_count }, CommonQueries);
+/// ```
+pub use juniper_compose_macros_ng::assert_fields_disjoint;
+
+/// Splits an existing `#[graphql_object]` impl into [composable objects](composable_object) and
+/// composes them back into a [composite object](composite_object) of the same name, to migrate a
+/// monolithic query type one group of fields at a time.
+///
+/// The members are listed after the name of the composite object with the methods of the impl
+/// each of them takes, and every method must be listed exactly once. Each member is declared as a
+/// unit struct implementing [Default](Default), with the visibility of the composite object and
+/// an impl of its methods carrying the attributes of the split impl, `#[graphql_object(...)]`
+/// included. The composite object has the context and scalar value type of the split impl, and
+/// its doc comments. The `name` and `description` arguments of `#[graphql_object(...)]` are taken
+/// off the member impls and name and describe the composite object, as `as "..."` and
+/// `#[graphql(description = "...")]` do, so the schema is unchanged. It declares the split type,
+/// so its own struct declaration is removed.
+///
+/// Methods are moved as they are, so one calling another with `Self::` has to be assigned to the
+/// same member. Once split, the generated members can be written out as
+/// `#[composable_object]` impls of their own and the invocation replaced by the
+/// [composite_object](composite_object) call it expands to.
+///
+/// ## Example
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object, graphql_value,
+/// };
+/// use juniper_compose::composable_split;
+///
+/// pub struct Context {
+///     users: Vec<String>,
+///     tasks: Vec<String>,
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// composable_split! {
+///     pub Query(UserQueries(user, users), TaskQueries(tasks));
+///
+///     /// Left out of the schema for the description below, as juniper does.
+///     #[graphql_object(name = "RootQuery", description = "Root of the schema", context = Context)]
+///     impl Query {
+///         fn user(context: &Context, index: i32) -> Option<String> {
+///             context.users.get(usize::try_from(index).ok()?).cloned()
+///         }
+///
+///         fn users(context: &Context) -> Vec<String> {
+///             context.users.clone()
+///         }
+///
+///         fn tasks(context: &Context) -> Vec<String> {
+///             context.tasks.clone()
+///         }
+///     }
+/// }
+///
+/// // Expands to the two members and
+/// // `composite_object!(pub Query as "RootQuery" <Context = Context>(UserQueries, TaskQueries))`,
+/// // described as "Root of the schema".
+/// let context = Context {
+///     users: vec!["ada".into(), "grace".into()],
+///     tasks: vec!["review".into()],
+/// };
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let query = r#"{
+///     user(index: 1)
+///     tasks
+///     __schema { queryType { name description } }
+/// }"#;
+/// let (data, errors) =
+///     juniper::execute_sync(query, None, &schema, &Variables::new(), &context).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(
+///     data,
+///     graphql_value!({
+///         "user": "grace",
+///         "tasks": ["review"],
+///         "__schema": {
+///             "queryType": { "name": "RootQuery", "description": "Root of the schema" },
+///         },
+///     }),
+/// );
+/// ```
+pub use juniper_compose_macros_ng::composable_split;
+
+/// Object types that you want to compose into one must implement this trait.
+/// Use [composable_object](composable_object) to implement it.
+///
+/// `S` is the scalar value type the object is resolved with. Objects that don't specify a
+/// scalar in `#[graphql_object]` implement this trait for every scalar value type.
+///
+/// The composite type constructs the object with [Default](Default) whenever one of its fields
+/// is resolved, unless it is listed as `from_context`, see
+/// [ComposableFromContext](ComposableFromContext).
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a composable object resolved with the scalar value type `{S}`",
+    note = "apply `#[composable_object]` before `#[graphql_object]` to make it composable",
+    note = "members of a composite type must share its scalar value type: a member declared with \
+            another `scalar` can't be composed, and one generic over it is listed as `Member<_>`"
+)]
+pub trait ComposableObject<S = DefaultScalarValue>: GraphQLTypeAsync<S>
+where
+    S: ScalarValue + Send + Sync,
+    Self::Context: Sync,
+    Self::TypeInfo: Sync,
+{
+    /// Name of the GraphQL type this object type registers under.
+    const TYPE_NAME: &'static str;
+
+    /// Names of the fields that exist on this object type, in the order they are declared. These
+    /// are their GraphQL names, which the composite resolves them by.
+    const FIELDS: &'static [&'static str];
+
+    /// Names of the fields of this object type that are resolved asynchronously, which can't be
+    /// resolved by juniper's synchronous execution.
+    const ASYNC_FIELDS: &'static [&'static str] = &[];
+
+    /// Names of the fields of this object type that its `rename_all` policy names differently from
+    /// their Rust name, such as juniper's default camelCase conversion, which composite objects
+    /// declared `rename_all = "none"` reject. Fields named explicitly aren't listed.
+    const CONVERTED_FIELDS: &'static [&'static str] = &[];
+
+    /// Whether [fields](Self::fields) lists fields that [FIELDS](Self::FIELDS) doesn't, because
+    /// they are only known at run time.
+    const DYNAMIC_FIELDS: bool = false;
+
+    /// GraphQL types of the fields that exist on this object type, in the order of
+    /// [FIELDS](Self::FIELDS), which composite objects check against the fields of the interfaces
+    /// they implement. Empty if they aren't known, which skips the check of the types.
+    const FIELD_TYPES: &'static [FieldType] = &[];
+
+    /// Returns the name of the GraphQL type this object type registers under, which is the name
+    /// juniper gives it, without building its meta type.
+    #[must_use]
+    fn type_name() -> &'static str {
+        Self::TYPE_NAME
+    }
+
+    /// Returns a list of fields that exist on this object type, which composites build their meta
+    /// type and dispatch fields with.
+    ///
+    /// Objects written by hand whose fields are only known at run time, such as generic objects
+    /// naming their fields after a type parameter, override it with names built by
+    /// [dynamic_fields](dynamic_fields), list no fields in [FIELDS](Self::FIELDS) and set
+    /// [`DYNAMIC_FIELDS`](Self::DYNAMIC_FIELDS). Composites expose these fields too, but can't
+    /// rename, exclude or select them, and only check them for collisions at run time: a
+    /// colliding field panics when the schema is built.
+    #[must_use]
+    fn fields() -> &'static [&'static str] {
+        Self::FIELDS
+    }
+
+    /// Returns the name, description, GraphQL type, deprecation status, deprecation reason and
+    /// arguments of each field that exists on this object type, in the order of
+    /// [fields](Self::fields). Arguments come with their description, type and default value.
+    ///
+    /// Built from the meta type on first call.
+    ///
+    /// ```
+    /// # extern crate juniper_compose_ng as juniper_compose;
+    /// use juniper::graphql_object;
+    /// use juniper_compose::{ComposableObject, composable_object};
+    ///
+    /// #[derive(Default)]
+    /// struct TaskQueries;
+    ///
+    /// #[composable_object]
+    /// #[graphql_object]
+    /// impl TaskQueries {
+    ///     fn tasks(
+    ///         #[graphql(description = "Number of tasks to return.", default = 10)] first: i32,
+    ///     ) -> Vec<i32> {
+    ///         (0..first).collect()
+    ///     }
+    /// }
+    ///
+    /// let argument = &<TaskQueries as ComposableObject>::fields_meta()[0].arguments[0];
+    /// assert_eq!(argument.name, "first");
+    /// assert_eq!(argument.description.as_deref(), Some("Number of tasks to return."));
+    /// assert_eq!(argument.arg_type.to_string(), "Int!");
+    /// assert_eq!(argument.default_value.as_deref(), Some("10"));
+    /// ```
+    #[must_use]
+    fn fields_meta() -> &'static [ComposableField]
+    where
+        Self: 'static,
+        Self::TypeInfo: Default,
+        S: 'static,
+    {
+        field::fields_meta::<Self, S>(Self::fields())
+    }
+
+    /// Returns the name and description of each field that exists on this object type, in the
+    /// order of [fields](Self::fields), e.g. to generate documentation without executing an
+    /// introspection query.
+    ///
+    /// [composable_object](composable_object) lists the descriptions juniper gives the fields,
+    /// from their `description` or doc comments. Built from the meta type on first call
+    /// otherwise, as by [`fields_meta`](Self::fields_meta).
+    #[must_use]
+    fn describe_fields() -> &'static [(&'static str, Option<&'static str>)]
+    where
+        Self: 'static,
+        Self::TypeInfo: Default,
+        S: 'static,
+    {
+        field::describe_fields::<Self, S>(Self::fields_meta())
+    }
+
+    /// Returns the name of each field that exists on this object type, in the order of
+    /// [fields](Self::fields), with the role resolving it requires, if any, e.g. for middleware to
+    /// authorize the fields of a query.
+    ///
+    /// [composable_object](composable_object) lists the roles given with
+    /// `#[auth(role = "...")]` on the resolvers, which juniper doesn't see, and composite objects
+    /// the roles of the fields their members expose, under their exposed names. No field requires
+    /// a role otherwise.
+    ///
+    /// ```
+    /// # extern crate juniper_compose_ng as juniper_compose;
+    /// use juniper::graphql_object;
+    /// use juniper_compose::{ComposableObject, composable_object, composite_object};
+    ///
+    /// #[derive(Default)]
+    /// struct UserQueries;
+    ///
+    /// #[composable_object]
+    /// #[graphql_object]
+    /// impl UserQueries {
+    ///     fn user_count() -> i32 {
+    ///         0
+    ///     }
+    ///
+    ///     #[auth(role = "admin")]
+    ///     fn audit_log() -> Vec<String> {
+    ///         Vec::new()
+    ///     }
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct TaskQueries;
+    ///
+    /// #[composable_object]
+    /// #[graphql_object]
+    /// impl TaskQueries {
+    ///     #[auth(role = "manager")]
+    ///     fn task_count() -> i32 {
+    ///         0
+    ///     }
+    /// }
+    ///
+    /// composite_object!(Query(prefix users UserQueries, TaskQueries));
+    ///
+    /// assert_eq!(
+    ///     <Query as ComposableObject>::fields_auth(),
+    ///     [
+    ///         ("usersUserCount", None),
+    ///         ("usersAuditLog", Some("admin")),
+    ///         ("taskCount", Some("manager")),
+    ///     ],
+    /// );
+    /// ```
+    #[must_use]
+    fn fields_auth() -> &'static [(&'static str, Option<&'static str>)]
+    where
+        Self: 'static,
+    {
+        field::fields_auth::<Self>(|| {
+            Self::fields()
+                .iter()
+                .map(|&field| (field.to_owned(), None))
+                .collect()
+        })
+    }
+}
+
+/// Subscription types that you want to compose into one must implement this trait.
+/// Use [composable_subscription](composable_subscription) to implement it.
+///
+/// Constructed the same way as [ComposableObject](ComposableObject)s.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a composable subscription resolved with the scalar value type `{S}`",
+    note = "apply `#[composable_subscription]` before `#[graphql_subscription]` to make it composable",
+    note = "members of a composite type must share its scalar value type: a member declared with \
+            another `scalar` can't be composed, and one generic over it is listed as `Member<_>`"
+)]
+pub trait ComposableSubscription<S = DefaultScalarValue>: GraphQLSubscriptionType<S>
+where
+    S: ScalarValue + Send + Sync,
+    Self::Context: Sync,
+    Self::TypeInfo: Sync,
+{
+    /// Names of the fields that exist on this subscription type.
+    const FIELDS: &'static [&'static str];
+
+    /// Returns a list of fields that exist on this subscription type.
+    #[must_use]
+    fn fields() -> &'static [&'static str] {
+        Self::FIELDS
+    }
+
+    /// Returns the name, description, GraphQL type, deprecation status and deprecation reason of
+    /// each field that exists on this subscription type, in the order of [fields](Self::fields).
+    ///
+    /// Built from the meta type on first call.
+    #[must_use]
+    fn fields_meta() -> &'static [ComposableField]
+    where
+        Self: 'static,
+        Self::TypeInfo: Default,
+        S: 'static,
+    {
+        field::fields_meta::<Self, S>(Self::fields())
+    }
+}
+
+/// Interface types that you want to compose into one must implement this trait.
+/// Use [composable_interface](composable_interface) to implement it.
+///
+/// Only the field definitions of the interface are used, the fields are resolved by the
+/// implementers of the composite interface.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a composable interface resolved with the scalar value type `{S}`",
+    note = "apply `#[composable_interface]` before `#[graphql_interface]` to make it composable",
+    note = "members of a composite type must share its scalar value type: a member declared with \
+            another `scalar` can't be composed, and one generic over it is listed as `Member<_>`"
+)]
+pub trait ComposableInterface<S = DefaultScalarValue>: GraphQLType<S>
+where
+    S: ScalarValue,
+{
+    /// Names of the fields that exist on this interface type.
+    const FIELDS: &'static [&'static str];
+
+    /// GraphQL types of the fields that exist on this interface type, in the order of
+    /// [FIELDS](Self::FIELDS). Empty if they aren't known, which skips the check of the types of
+    /// the fields of the composite objects implementing it.
+    const FIELD_TYPES: &'static [FieldType] = &[];
+
+    /// Returns a list of fields that exist on this interface type.
+    #[must_use]
+    fn fields() -> &'static [&'static str] {
+        Self::FIELDS
+    }
+
+    /// Returns the name, description, GraphQL type, deprecation status, deprecation reason and
+    /// arguments of each field that exists on this interface type, in the order of
+    /// [fields](Self::fields).
+    ///
+    /// Built from the meta type on first call.
+    #[must_use]
+    fn fields_meta() -> &'static [ComposableField]
+    where
+        Self: 'static,
+        Self::TypeInfo: Default,
+        S: 'static,
+    {
+        field::fields_meta::<Self, S>(Self::fields())
+    }
+}
+
+/// Input object types that you want to compose into one must implement this trait.
+/// Use [composable_input_object](composable_input_object) to implement it.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a composable input object resolved with the scalar value type `{S}`",
+    note = "apply `#[composable_input_object]` before `#[derive(GraphQLInputObject)]` to make it composable",
+    note = "members of a composite type must share its scalar value type: a member declared with \
+            another `scalar` can't be composed, and one generic over it is listed as `Member<_>`"
+)]
+pub trait ComposableInputObject<S = DefaultScalarValue>:
+    GraphQLType<S> + FromInputValue<S> + ToInputValue<S>
+where
+    S: ScalarValue,
+{
+    /// Names of the fields that exist on this input object type.
+    const FIELDS: &'static [&'static str];
+
+    /// Returns a list of fields that exist on this input object type.
+    #[must_use]
+    fn fields() -> &'static [&'static str] {
+        Self::FIELDS
+    }
+}
+
+/// Union types that you want to compose into one must implement this trait.
+/// Use [composable_union](composable_union) to implement it.
+///
+/// `S` is the scalar value type the union is resolved with. Unions that don't specify a scalar in
+/// `#[graphql(...)]` implement this trait for every scalar value type.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a composable union resolved with the scalar value type `{S}`",
+    note = "apply `#[composable_union]` before `#[derive(GraphQLUnion)]` to make it composable",
+    note = "members of a composite type must share its scalar value type: a member declared with \
+            another `scalar` can't be composed, and one generic over it is listed as `Member<_>`"
+)]
+pub trait ComposableUnion<S = DefaultScalarValue>: GraphQLTypeAsync<S>
+where
+    S: ScalarValue + Send + Sync,
+    Self::Context: Sync,
+    Self::TypeInfo: Sync,
+{
+    /// Names of the GraphQL types that are variants of this union type.
+    const VARIANTS: &'static [&'static str];
+
+    /// Returns a list of the GraphQL types that are variants of this union type.
+    #[must_use]
+    fn variants() -> &'static [&'static str] {
+        Self::VARIANTS
+    }
+}
+
+/// Composable types that are constructed from the context instead of with [Default](Default).
+/// Listed as `from_context MyQueries` in [composite_object](composite_object) and
+/// [composite_subscription](composite_subscription).
+///
+/// ## Example
+///
+/// ```ignore
+/// use juniper_compose_ng::{ComposableFromContext, composable_object, composite_object};
+/// use juniper::graphql_object;
+///
+/// struct Context { users: UserRepository }
+///
+/// struct UserQueries { users: UserRepository }
+///
+/// impl ComposableFromContext for UserQueries {
+///     fn from_context(context: &Context) -> Self {
+///         Self { users: context.users.clone() }
+///     }
+/// }
+///
+/// #[composable_object]
+/// #[graphql_object(context = Context)]
+/// impl UserQueries {
+///     // ...
+/// }
+///
+/// #[derive(Default)] struct TaskQueries;
+///
+/// composite_object!(Query<Context = Context>(from_context UserQueries, TaskQueries));
+/// ```
+pub trait ComposableFromContext<S = DefaultScalarValue>: GraphQLValue<S>
+where
+    S: ScalarValue,
+{
+    /// Constructs the composable type to resolve a field of the composite type with.
+    fn from_context(context: &Self::Context) -> Self;
+}
+
+/// Composable objects whose fields can be disabled at run time, e.g. by feature flags in the
+/// context. Listed as `gated MyQueries` in [composite_object](composite_object), which asks the
+/// object whether a field is enabled before resolving it, and resolves a disabled field to the
+/// error of [unknown_field](unknown_field), under the name it is exposed under.
+///
+/// Disabled fields are still part of the schema, so introspection lists them and queries
+/// selecting them pass validation. Only their resolution fails, field by field: the error nulls
+/// the disabled field, or its parent if the field is non-null, as GraphQL propagates nulls, and
+/// the rest of the query is resolved as usual.
+///
+/// ## Example
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, RootNode, Variables, graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{FieldGate, composable_object, composite_object};
+///
+/// struct Context {
+///     beta: bool,
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// #[derive(Default)]
+/// struct SearchQueries;
+///
+/// #[composable_object]
+/// #[graphql_object(context = Context)]
+/// impl SearchQueries {
+///     fn search() -> Vec<i32> {
+///         vec![1]
+///     }
+///
+///     fn semantic_search() -> Option<Vec<i32>> {
+///         Some(vec![2])
+///     }
+/// }
+///
+/// impl FieldGate for SearchQueries {
+///     fn is_field_enabled(context: &Context, field_name: &str) -> bool {
+///         field_name != "semanticSearch" || context.beta
+///     }
+/// }
+///
+/// composite_object!(Query<Context = Context>(gated SearchQueries { semantic_search: ai_search }));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let query = "{ search aiSearch }";
+/// let beta = Context { beta: true };
+/// let (data, errors) =
+///     juniper::execute_sync(query, None, &schema, &Variables::new(), &beta).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(data, graphql_value!({ "search": [1], "aiSearch": [2] }));
+///
+/// let stable = Context { beta: false };
+/// let (data, errors) =
+///     juniper::execute_sync(query, None, &schema, &Variables::new(), &stable).unwrap();
+/// assert_eq!(data, graphql_value!({ "search": [1], "aiSearch": null }));
+/// assert_eq!(errors[0].error().message(), "Field `aiSearch` not found on type `Query`");
+/// ```
+pub trait FieldGate<S = DefaultScalarValue>: GraphQLValue<S>
+where
+    S: ScalarValue,
+{
+    /// Returns whether the field `field_name` of this object type, as named by the object itself
+    /// rather than the composite, can be resolved in `context`.
+    fn is_field_enabled(context: &Self::Context, field_name: &str) -> bool;
+}
+
+/// Converts `ty` to a type that owns the names it refers to, so that it can outlive the meta
+/// types it was borrowed from. Lists keep their nesting, nullability and expected size, which
+/// arguments of composed fields are validated and coerced with.
+///
+/// ```
+/// use juniper::Type;
+/// use juniper_compose_ng::{type_to_borrowed, type_to_owned};
+/// use std::borrow::Cow;
+///
+/// // `[[User!]]!`
+/// let name = String::from("User");
+/// let ty = Type::NonNullList(
+///     Box::new(Type::List(
+///         Box::new(Type::NonNullNamed(Cow::Borrowed(name.as_str()))),
+///         None,
+///     )),
+///     None,
+/// );
+///
+/// let owned: Type<'static> = type_to_owned(&ty);
+/// assert_eq!(owned, ty);
+/// assert_eq!(type_to_borrowed(&owned), ty);
+///
+/// // `[[Int!]!]!`, the inner lists of length 2, as for a `[[i32; 2]; 2]` argument.
+/// let ty = Type::NonNullList(
+///     Box::new(Type::NonNullList(
+///         Box::new(Type::NonNullNamed(Cow::Borrowed("Int"))),
+///         Some(2),
+///     )),
+///     Some(2),
+/// );
+/// assert_eq!(type_to_owned(&ty), ty);
+/// assert_eq!(type_to_owned(&ty).to_string(), "[[Int!]!]!");
+/// ```
+///
+/// Composite objects rebuild the arguments of the fields they expose with it, so a nested list
+/// argument reaches the composed resolver as it is given, and is validated against its full type:
+///
+/// ```
+/// # extern crate juniper_compose_ng as juniper_compose;
+/// use juniper::{
+///     EmptyMutation, EmptySubscription, GraphQLError, RootNode, Variables, graphql_input_value,
+///     graphql_object, graphql_value,
+/// };
+/// use juniper_compose::{composable_object, composite_object};
+///
+/// #[derive(Default)]
+/// struct GridQueries;
+///
+/// #[composable_object]
+/// #[graphql_object]
+/// impl GridQueries {
+///     /// Takes a `[[Int!]!]!` argument.
+///     fn grid(grid: Vec<Vec<i32>>) -> String {
+///         format!("{grid:?}")
+///     }
+/// }
+///
+/// composite_object!(Query(prefix my GridQueries));
+///
+/// let schema = RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new());
+/// let query = "query($grid: [[Int!]!]!) { myGrid(grid: $grid) literal: myGrid(grid: [[4], []]) }";
+/// let mut variables = Variables::new();
+/// variables.insert("grid".into(), graphql_input_value!([[1, 2], [3]]));
+/// let (data, errors) = juniper::execute_sync(query, None, &schema, &variables, &()).unwrap();
+/// assert!(errors.is_empty());
+/// assert_eq!(data, graphql_value!({ "myGrid": "[[1, 2], [3]]", "literal": "[[4], []]" }));
+///
+/// let query = "{ myGrid(grid: [[null]]) }";
+/// let result = juniper::execute_sync(query, None, &schema, &variables, &());
+/// assert!(matches!(result, Err(GraphQLError::ValidationError(_))));
+/// ```
+#[must_use]
+pub fn type_to_owned(ty: &Type<'_>) -> Type<'static> {
+    map_type_names(ty, &|name| Cow::Owned(name.to_owned()))
+}
+
+/// Converts `ty` to a type that borrows the names it refers to from `ty`, which is the inverse of
+/// [`type_to_owned`] without copying the names.
+#[must_use]
+pub fn type_to_borrowed<'a>(ty: &'a Type<'_>) -> Type<'a> {
+    map_type_names(ty, &Cow::Borrowed)
+}
+
+/// Rebuilds `ty`, with lists nested the same way, converting each named type with `name`.
+fn map_type_names<'a, 'b>(ty: &'a Type<'_>, name: &impl Fn(&'a str) -> Cow<'b, str>) -> Type<'b> {
     match ty {
-        Type::Named(name) => Type::Named(Cow::Owned(name.to_string())),
-        Type::NonNullNamed(name) => Type::NonNullNamed(Cow::Owned(name.to_string())),
-        Type::List(inner, size) => Type::List(Box::new(type_to_owned(inner)), *size),
-        Type::NonNullList(inner, size) => Type::NonNullList(Box::new(type_to_owned(inner)), *size),
+        Type::Named(inner) => Type::Named(name(inner)),
+        Type::NonNullNamed(inner) => Type::NonNullNamed(name(inner)),
+        Type::List(inner, size) => Type::List(Box::new(map_type_names(inner, name)), *size),
+        Type::NonNullList(inner, size) => {
+            Type::NonNullList(Box::new(map_type_names(inner, name)), *size)
+        }
     }
 }
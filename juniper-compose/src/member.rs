@@ -0,0 +1,702 @@
+//! Description of a composable type as it is listed in a composite type.
+//!
+//! Shared by the code generated by [composite_object](crate::composite_object) and the
+//! compile-time checks in [check](crate::check), so that both agree on which name each field is
+//! exposed under.
+
+use crate::{FieldType, check::str_eq};
+use juniper::meta::DeprecationStatus;
+use std::fmt;
+
+/// A composable type as it is listed in a composite type.
+pub struct Member {
+    /// Rust name of the composable type, for error messages.
+    pub type_name: &'static str,
+    /// Fields that exist on the composable type.
+    pub fields: &'static [&'static str],
+    /// `(field, exposed name)` pairs for fields the composite exposes under a different name.
+    pub renames: &'static [(&'static str, &'static str)],
+    /// `(field, alias, reason)` entries for the deprecated names the composite exposes fields
+    /// under as well as their exposed name, in order, with the deprecation reason given in place
+    /// of the generated one, if any.
+    pub aliases: &'static [(&'static str, &'static str, Option<&'static str>)],
+    /// Prefix of the fields that aren't renamed, already in camelCase.
+    pub prefix: &'static str,
+    /// Fields the composite doesn't expose.
+    pub excluded: &'static [&'static str],
+    /// Fields the composite exposes, if it doesn't expose every field that isn't excluded.
+    pub only: Option<&'static [&'static str]>,
+    /// Case the composite converts the exposed names to, from its `rename_all`.
+    pub case: Case,
+    /// Whether the member is the `fallback` of the composite, which leaves out its fields that
+    /// another member exposes as well, and resolves the fields no other member exposes.
+    pub fallback: bool,
+}
+
+/// Case the exposed names of the fields of a composite are converted to.
+#[derive(Clone, Copy)]
+pub enum Case {
+    /// Names are exposed as they are, which `rename_all = "none"` and no `rename_all` mean.
+    Unchanged,
+    /// `rename_all = "camelCase"`: underscores are removed and the letter following an
+    /// underscore is capitalized, like juniper does.
+    Camel,
+    /// `rename_all = "snake_case"`: words starting with a capital letter are separated by an
+    /// underscore and lowercased.
+    Snake,
+}
+
+impl Member {
+    /// Returns `true` if the composite exposes `field` of this member.
+    #[must_use]
+    pub const fn exposes(&self, field: &str) -> bool {
+        if contains(self.excluded, field) {
+            return false;
+        }
+        match self.only {
+            Some(only) => contains(only, field),
+            None => true,
+        }
+    }
+
+    /// Returns the name the composite exposes `field` of this member under.
+    #[must_use]
+    pub const fn exposed_name(&self, field: &'static str) -> ExposedName {
+        let mut i = 0;
+        while i < self.renames.len() {
+            if str_eq(self.renames[i].0, field) {
+                return ExposedName {
+                    prefix: "",
+                    name: self.renames[i].1,
+                    case: self.case,
+                };
+            }
+            i += 1;
+        }
+        ExposedName {
+            prefix: self.prefix,
+            name: field,
+            case: self.case,
+        }
+    }
+
+    /// Returns `true` if the composite exposes `field` of this member under a name of its own,
+    /// given in the member's `{ ... }` block.
+    #[must_use]
+    pub const fn is_renamed(&self, field: &str) -> bool {
+        let mut i = 0;
+        while i < self.renames.len() {
+            if str_eq(self.renames[i].0, field) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Returns the number of names the composite exposes `field` of this member under: none if
+    /// it doesn't expose it, otherwise its exposed name and its aliases.
+    #[must_use]
+    pub const fn name_count(&self, field: &str) -> usize {
+        if !self.exposes(field) {
+            return 0;
+        }
+        let mut count = 1;
+        let mut i = 0;
+        while i < self.aliases.len() {
+            if str_eq(self.aliases[i].0, field) {
+                count += 1;
+            }
+            i += 1;
+        }
+        count
+    }
+
+    /// Returns the name at `index` among the names the composite exposes `field` of this member
+    /// under: its exposed name, then its aliases. `index` must be less than
+    /// [`name_count`](Self::name_count).
+    #[must_use]
+    pub const fn name(&self, field: &'static str, index: usize) -> ExposedName {
+        if index == 0 {
+            return self.exposed_name(field);
+        }
+        ExposedName {
+            prefix: "",
+            name: self.alias(field, index).1,
+            case: self.case,
+        }
+    }
+
+    /// Returns the deprecation status of the name at `index` among the names the composite
+    /// exposes `field` of this member under, `status` being the one of the field. Aliases of a
+    /// field that isn't deprecated itself are deprecated for the reason given with them, or
+    /// "Renamed to `exposedName`.". `index` must be less than [`name_count`](Self::name_count).
+    #[must_use]
+    pub fn deprecation_status(
+        &self,
+        field: &'static str,
+        index: usize,
+        status: &DeprecationStatus,
+    ) -> DeprecationStatus {
+        if index == 0 || status.is_deprecated() {
+            return status.clone();
+        }
+        DeprecationStatus::Deprecated(Some(match self.alias(field, index).2 {
+            Some(reason) => reason.to_owned(),
+            None => format!("Renamed to `{}`.", self.exposed_name(field)),
+        }))
+    }
+
+    /// Returns the alias of `field` at `index` among the names the composite exposes it under.
+    const fn alias(
+        &self,
+        field: &str,
+        index: usize,
+    ) -> &'static (&'static str, &'static str, Option<&'static str>) {
+        let mut aliases = 0;
+        let mut i = 0;
+        loop {
+            if str_eq(self.aliases[i].0, field) {
+                aliases += 1;
+                if aliases == index {
+                    return &self.aliases[i];
+                }
+            }
+            i += 1;
+        }
+    }
+
+    /// Returns `true` if the composite exposes a field of this member under `name`.
+    #[must_use]
+    pub const fn exposes_name(&self, name: &ExposedName) -> bool {
+        let mut i = 0;
+        while i < self.fields.len() {
+            let mut j = 0;
+            while j < self.name_count(self.fields[i]) {
+                if name.eq(&self.name(self.fields[i], j)) {
+                    return true;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Returns `true` if the composite made of `members` leaves out `name`, one of the names of
+    /// the fields of this member, because this member is its fallback and another member
+    /// exposes a field under `name`.
+    #[must_use]
+    pub const fn is_shadowed(&self, name: &ExposedName, members: &[Member]) -> bool {
+        if !self.fallback {
+            return false;
+        }
+        let mut i = 0;
+        while i < members.len() {
+            if !members[i].fallback && members[i].exposes_name(name) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Returns the field of this member that the composite exposes under `name`, if any.
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&'static str> {
+        self.fields
+            .iter()
+            .copied()
+            .find(|field| (0..self.name_count(field)).any(|index| self.name(field, index).is(name)))
+    }
+}
+
+/// Name a field is exposed under: `name`, or `prefix` followed by `name` with its first letter
+/// capitalized, converted to `case`.
+#[derive(Clone, Copy)]
+pub struct ExposedName {
+    prefix: &'static str,
+    name: &'static str,
+    case: Case,
+}
+
+impl ExposedName {
+    /// Returns the length of the name in bytes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        if let Case::Unchanged = self.case {
+            return self.prefix.len() + self.name.len();
+        }
+        let mut len = 0;
+        let mut bytes = self.bytes();
+        while bytes.next().is_some() {
+            len += 1;
+        }
+        len
+    }
+
+    /// Returns `true` if the name is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the byte at `index`, which must be less than [`len`](Self::len).
+    #[must_use]
+    pub const fn byte(&self, index: usize) -> u8 {
+        let mut bytes = self.bytes();
+        let mut i = 0;
+        loop {
+            match bytes.next() {
+                Some(byte) if i == index => return byte,
+                Some(_) => i += 1,
+                None => panic!("index out of bounds of the exposed name"),
+            }
+        }
+    }
+
+    /// Returns the bytes of the name, converted in a single pass over the unconverted name.
+    #[must_use]
+    pub const fn bytes(&self) -> ExposedBytes {
+        ExposedBytes {
+            name: *self,
+            index: 0,
+            pending: None,
+            has_word: false,
+        }
+    }
+
+    /// Returns `true` if the name is exposed as it is, so that it needn't be converted.
+    const fn is_verbatim(&self) -> bool {
+        matches!(self.case, Case::Unchanged) && self.prefix.is_empty()
+    }
+
+    const fn unconverted_len(&self) -> usize {
+        self.prefix.len() + self.name.len()
+    }
+
+    const fn unconverted_byte(&self, index: usize) -> u8 {
+        if index < self.prefix.len() {
+            self.prefix.as_bytes()[index]
+        } else if index == self.prefix.len() && !self.prefix.is_empty() {
+            self.name.as_bytes()[0].to_ascii_uppercase()
+        } else {
+            self.name.as_bytes()[index - self.prefix.len()]
+        }
+    }
+
+    /// Returns `true` if both names are the same.
+    #[must_use]
+    pub const fn eq(&self, other: &Self) -> bool {
+        if self.is_verbatim() && other.is_verbatim() {
+            return str_eq(self.name, other.name);
+        }
+        let mut bytes = self.bytes();
+        let mut other = other.bytes();
+        loop {
+            match (bytes.next(), other.next()) {
+                (None, None) => return true,
+                (Some(byte), Some(other)) if byte == other => {}
+                _ => return false,
+            }
+        }
+    }
+
+    /// Returns `true` if this is the name `name`.
+    #[must_use]
+    pub const fn is(&self, name: &str) -> bool {
+        if self.is_verbatim() {
+            return str_eq(self.name, name);
+        }
+        let name = name.as_bytes();
+        let mut bytes = self.bytes();
+        let mut i = 0;
+        loop {
+            match bytes.next() {
+                None => return i == name.len(),
+                Some(byte) if i < name.len() && byte == name[i] => i += 1,
+                Some(_) => return false,
+            }
+        }
+    }
+}
+
+impl fmt::Display for ExposedName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut bytes = Vec::with_capacity(self.unconverted_len());
+        let mut converted = self.bytes();
+        while let Some(byte) = converted.next() {
+            bytes.push(byte);
+        }
+        // Only ASCII bytes are converted, so the name stays valid UTF-8.
+        f.write_str(&String::from_utf8_lossy(&bytes))
+    }
+}
+
+/// Bytes of an [`ExposedName`], returned by [`ExposedName::bytes`].
+///
+/// Whether a byte starts a word depends on the bytes around it, which the cursor keeps track of,
+/// so that comparing names stays linear in their length during constant evaluation.
+pub struct ExposedBytes {
+    name: ExposedName,
+    /// Index of the next byte of the unconverted name.
+    index: usize,
+    /// Byte to return before converting the next one, when a byte is converted to two.
+    pending: Option<u8>,
+    /// Whether a byte before `index` of the unconverted name isn't an underscore.
+    has_word: bool,
+}
+
+impl ExposedBytes {
+    /// Returns the next byte of the name, if any.
+    #[allow(clippy::should_implement_trait)]
+    pub const fn next(&mut self) -> Option<u8> {
+        if let Some(byte) = self.pending {
+            self.pending = None;
+            return Some(byte);
+        }
+        let name = &self.name;
+        while self.index < name.unconverted_len() {
+            let index = self.index;
+            let byte = name.unconverted_byte(index);
+            self.index += 1;
+            match name.case {
+                Case::Unchanged => return Some(byte),
+                Case::Camel => {
+                    if byte == b'_' {
+                        continue;
+                    }
+                    let starts_word =
+                        index > 0 && name.unconverted_byte(index - 1) == b'_' && self.has_word;
+                    self.has_word = true;
+                    return Some(if starts_word {
+                        byte.to_ascii_uppercase()
+                    } else {
+                        byte
+                    });
+                }
+                Case::Snake => {
+                    if !byte.is_ascii_uppercase() {
+                        return Some(byte);
+                    }
+                    let starts_word = index > 0 && {
+                        let previous = name.unconverted_byte(index - 1);
+                        previous.is_ascii_lowercase()
+                            || previous.is_ascii_digit()
+                            || previous.is_ascii_uppercase()
+                                && index + 1 < name.unconverted_len()
+                                && name.unconverted_byte(index + 1).is_ascii_lowercase()
+                    };
+                    if starts_word {
+                        self.pending = Some(byte.to_ascii_lowercase());
+                        return Some(b'_');
+                    }
+                    return Some(byte.to_ascii_lowercase());
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Returns `true` if any of `flags`, the `DYNAMIC_FIELDS` of the members of a composite, is set.
+#[must_use]
+pub const fn any(flags: &[bool]) -> bool {
+    let mut i = 0;
+    while i < flags.len() {
+        if flags[i] {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns the names the composite made of `members` exposes their fields under, in order, like
+/// [`exposed_names`] but from the names of their fields at run time.
+#[must_use]
+pub fn exposed_field_names(members: &[Member]) -> Vec<String> {
+    members
+        .iter()
+        .flat_map(|member| {
+            member.fields.iter().flat_map(move |&field| {
+                (0..member.name_count(field))
+                    .map(move |name| member.name(field, name))
+                    .filter(move |name| !member.is_shadowed(name, members))
+                    .map(|name| name.to_string())
+            })
+        })
+        .collect()
+}
+
+/// Returns the names the composite made of `members` exposes their fields under, in order, like
+/// [`exposed_field_names`], each with the role it requires according to `fields_auth`, the
+/// fields and roles of each member.
+#[must_use]
+pub fn exposed_fields_auth(
+    members: &[Member],
+    fields_auth: &[&[(&'static str, Option<&'static str>)]],
+) -> Vec<(String, Option<&'static str>)> {
+    members
+        .iter()
+        .zip(fields_auth)
+        .flat_map(|(member, fields_auth)| {
+            member.fields.iter().flat_map(move |&field| {
+                let role = fields_auth
+                    .iter()
+                    .find(|(name, _)| *name == field)
+                    .and_then(|(_, role)| *role);
+                (0..member.name_count(field))
+                    .map(move |name| member.name(field, name))
+                    .filter(move |name| !member.is_shadowed(name, members))
+                    .map(move |name| (name.to_string(), role))
+            })
+        })
+        .collect()
+}
+
+/// Table locating the member that resolves a field of the composite made of `members`, sorted by
+/// exposed name so that a field is found by binary search rather than by comparing it with the
+/// name of every field in turn.
+pub struct DispatchTable {
+    /// `(exposed name, member index, field)` of every field, sorted by exposed name.
+    entries: Vec<(String, usize, &'static str)>,
+    /// Index and type name of every member.
+    type_names: Vec<(usize, &'static str)>,
+}
+
+impl DispatchTable {
+    /// Builds the table of the composite made of `members`, each along with the index it is
+    /// listed at. Members left out by a `cfg` attribute are missing, so indices may be skipped.
+    #[must_use]
+    pub fn new(members: &[(usize, Member)]) -> Self {
+        let claimed = |name: &ExposedName| {
+            members
+                .iter()
+                .any(|(_, member)| !member.fallback && member.exposes_name(name))
+        };
+        let mut entries = members
+            .iter()
+            .flat_map(|(index, member)| {
+                member.fields.iter().flat_map(move |&field| {
+                    (0..member.name_count(field))
+                        .map(move |name| member.name(field, name))
+                        .filter(move |name| !member.fallback || !claimed(name))
+                        .map(move |name| (name.to_string(), *index, field))
+                })
+            })
+            .collect::<Vec<_>>();
+        // The sort is stable, so if two members exposed the same name, the first one listed would
+        // still resolve it.
+        entries.sort_by(|left, right| left.0.cmp(&right.0));
+        Self {
+            entries,
+            type_names: members
+                .iter()
+                .map(|(index, member)| (*index, member.type_name))
+                .collect(),
+        }
+    }
+
+    /// Returns the index of the member that resolves the field exposed under `name`, along with
+    /// the name of the field on that member.
+    #[must_use]
+    pub fn find(&self, name: &str) -> Option<(usize, &'static str)> {
+        let index = self
+            .entries
+            .partition_point(|(exposed_name, _, _)| exposed_name.as_str() < name);
+        match self.entries.get(index) {
+            Some((exposed_name, member, field)) if exposed_name == name => Some((*member, field)),
+            _ => None,
+        }
+    }
+
+    /// Returns the type name of the member that resolves the field exposed under `name`.
+    #[must_use]
+    pub fn origin(&self, name: &str) -> Option<&'static str> {
+        let (member, _) = self.find(name)?;
+        self.type_names
+            .iter()
+            .find(|(index, _)| *index == member)
+            .map(|(_, type_name)| *type_name)
+    }
+}
+
+const fn contains(fields: &[&str], field: &str) -> bool {
+    let mut i = 0;
+    while i < fields.len() {
+        if str_eq(fields[i], field) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns the number of fields the composite made of `members` exposes.
+#[must_use]
+pub const fn field_count(members: &[Member]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < members.len() {
+        let member = &members[i];
+        let mut j = 0;
+        while j < member.fields.len() {
+            let mut k = 0;
+            while k < member.name_count(member.fields[j]) {
+                if !member.is_shadowed(&member.name(member.fields[j], k), members) {
+                    count += 1;
+                }
+                k += 1;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+/// Returns the total length in bytes of the names of the fields the composite made of `members`
+/// exposes.
+#[must_use]
+pub const fn exposed_names_len(members: &[Member]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < members.len() {
+        let member = &members[i];
+        let mut j = 0;
+        while j < member.fields.len() {
+            let mut k = 0;
+            while k < member.name_count(member.fields[j]) {
+                let name = member.name(member.fields[j], k);
+                if !member.is_shadowed(&name, members) {
+                    len += name.len();
+                }
+                k += 1;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    len
+}
+
+/// Returns the names of the fields the composite made of `members` exposes, concatenated.
+/// `N` must be [`exposed_names_len`] of `members`.
+#[must_use]
+pub const fn exposed_names_bytes<const N: usize>(members: &[Member]) -> [u8; N] {
+    let mut bytes = [0; N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < members.len() {
+        let member = &members[i];
+        let mut j = 0;
+        while j < member.fields.len() {
+            let mut k = 0;
+            while k < member.name_count(member.fields[j]) {
+                let name = member.name(member.fields[j], k);
+                k += 1;
+                if member.is_shadowed(&name, members) {
+                    continue;
+                }
+                let mut name = name.bytes();
+                while let Some(byte) = name.next() {
+                    bytes[len] = byte;
+                    len += 1;
+                }
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    bytes
+}
+
+/// Returns the names of the fields the composite made of `members` exposes, in order, as slices of
+/// `bytes` built by [`exposed_names_bytes`]. `N` must be [`field_count`] of `members`.
+#[must_use]
+pub const fn exposed_names<const N: usize>(
+    members: &[Member],
+    bytes: &'static [u8],
+) -> [&'static str; N] {
+    let mut names = [""; N];
+    let mut rest = bytes;
+    let mut count = 0;
+    let mut i = 0;
+    while i < members.len() {
+        let member = &members[i];
+        let mut j = 0;
+        while j < member.fields.len() {
+            let mut k = 0;
+            while k < member.name_count(member.fields[j]) {
+                let name = member.name(member.fields[j], k);
+                k += 1;
+                if member.is_shadowed(&name, members) {
+                    continue;
+                }
+                let (name, tail) = rest.split_at(name.len());
+                names[count] = match core::str::from_utf8(name) {
+                    Ok(name) => name,
+                    Err(_) => panic!("field names must be valid UTF-8"),
+                };
+                rest = tail;
+                count += 1;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    names
+}
+
+/// Returns the number of field types the composite made of `members` knows, `types` being the
+/// `FIELD_TYPES` of each of them: [`field_count`] of `members` if every member knows the types of
+/// its fields, none otherwise.
+#[must_use]
+pub const fn field_types_len(members: &[Member], types: &[&[FieldType]]) -> usize {
+    let mut i = 0;
+    while i < members.len() {
+        if types[i].len() != members[i].fields.len() {
+            return 0;
+        }
+        i += 1;
+    }
+    field_count(members)
+}
+
+/// Returns the types of the fields the composite made of `members` exposes, in the order of
+/// [`exposed_names`], `types` being the `FIELD_TYPES` of each of them. `N` must be
+/// [`field_types_len`] of `members`, so that they are empty if a member doesn't know them.
+#[must_use]
+pub const fn exposed_field_types<const N: usize>(
+    members: &[Member],
+    types: &[&[FieldType]],
+) -> [FieldType; N] {
+    let mut exposed = [FieldType {
+        name: "",
+        sub_types: &[],
+        wrapped: 1,
+    }; N];
+    if N == 0 {
+        return exposed;
+    }
+    let mut count = 0;
+    let mut i = 0;
+    while i < members.len() {
+        let member = &members[i];
+        let mut j = 0;
+        while j < member.fields.len() {
+            let mut k = 0;
+            while k < member.name_count(member.fields[j]) {
+                if !member.is_shadowed(&member.name(member.fields[j], k), members) {
+                    exposed[count] = types[i][j];
+                    count += 1;
+                }
+                k += 1;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    exposed
+}
@@ -0,0 +1,60 @@
+//! Registry of the composite objects of a program, with the `inventory` feature.
+//!
+//! Every [`composite_object`](crate::composite_object) invocation of every crate linked into the
+//! program registers the composite object it generates, e.g. to generate a report of how the
+//! schema is composed without listing the composites by hand:
+//!
+//! ```
+//! # extern crate juniper_compose_ng as juniper_compose;
+//! use juniper::graphql_object;
+//! use juniper_compose::{composable_object, composite_object, registry};
+//!
+//! #[derive(Default)]
+//! struct UserQueries;
+//!
+//! #[composable_object]
+//! #[graphql_object]
+//! impl UserQueries {
+//!     fn user_count() -> i32 {
+//!         2
+//!     }
+//! }
+//!
+//! #[derive(Default)]
+//! struct TaskQueries;
+//!
+//! #[composable_object]
+//! #[graphql_object]
+//! impl TaskQueries {
+//!     fn task_count() -> i32 {
+//!         3
+//!     }
+//! }
+//!
+//! composite_object!(Query(UserQueries, TaskQueries));
+//!
+//! let query = registry::composites()
+//!     .find(|composite| composite.name == "Query")
+//!     .unwrap();
+//! assert!(query.type_path.ends_with("::Query"));
+//! assert_eq!(query.members, ["UserQueries", "TaskQueries"]);
+//! ```
+
+/// A composite object, as registered by [`composite_object`](crate::composite_object).
+#[derive(Debug)]
+pub struct Composite {
+    /// GraphQL name of the composite object.
+    pub name: &'static str,
+    /// Path of the generated type, from the crate root of the crate declaring it.
+    pub type_path: &'static str,
+    /// GraphQL names of the composed objects, in the order they are listed, leaving out the ones
+    /// that are conditionally compiled out. Inline fields and `federation` add none.
+    pub members: &'static [&'static str],
+}
+
+inventory::collect!(Composite);
+
+/// Returns every composite object of the program, in no particular order.
+pub fn composites() -> impl Iterator<Item = &'static Composite> {
+    inventory::iter::<Composite>.into_iter()
+}
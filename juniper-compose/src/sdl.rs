@@ -0,0 +1,354 @@
+//! Schema definition language of schemas made of composite types, e.g. for snapshot tests.
+//!
+//! Juniper can only print SDL with its `schema-language` feature, which pulls in a GraphQL
+//! parser. This printer relies on the meta types alone.
+
+use juniper::{
+    GraphQLType, RootNode, ScalarValue, SchemaType,
+    meta::{Argument, DeprecationStatus, Field, MetaType},
+};
+use std::fmt::Write as _;
+
+use crate::federation::{SERVICE_FIELD, SERVICE_TYPE};
+
+/// Names of the scalars every schema has, which are left out of the SDL.
+const BUILT_IN_SCALARS: &[&str] = &["Boolean", "Float", "ID", "Int", "String"];
+
+/// Names of the directives every schema has, which are left out of the SDL.
+const BUILT_IN_DIRECTIVES: &[&str] = &["deprecated", "include", "skip", "specifiedBy"];
+
+/// Returns the SDL of the schema made of the query type `Q`, the mutation type `M` and the
+/// subscription type `Sub`, constructed with [Default](Default).
+///
+/// Definitions are sorted by kind, then by name, so that the SDL is the same from build to build.
+/// Introspection types, built-in scalars and built-in directives are left out.
+///
+/// ## Example
+///
+/// ```ignore
+/// use juniper::{DefaultScalarValue, EmptyMutation, EmptySubscription};
+/// use juniper_compose_ng::{composite_object, schema_sdl};
+///
+/// composite_object!(Query<Context = Context>(UserQueries, TaskQueries));
+///
+/// let sdl = schema_sdl::<
+///     Query,
+///     EmptyMutation<Context>,
+///     EmptySubscription<Context>,
+///     DefaultScalarValue,
+/// >();
+/// ```
+#[must_use]
+pub fn schema_sdl<Q, M, Sub, S>() -> String
+where
+    Q: GraphQLType<S, TypeInfo = ()> + Default,
+    M: GraphQLType<S, TypeInfo = ()> + Default,
+    Sub: GraphQLType<S, TypeInfo = ()> + Default,
+    S: ScalarValue,
+{
+    root_node_sdl(&RootNode::new_with_scalar_value(
+        Q::default(),
+        M::default(),
+        Sub::default(),
+    ))
+}
+
+/// Returns the SDL of `root_node`, like [`schema_sdl`] does for the root node it builds, e.g. for
+/// root types with type info.
+#[must_use]
+pub fn root_node_sdl<Q, M, Sub, S>(root_node: &RootNode<'_, Q, M, Sub, S>) -> String
+where
+    Q: GraphQLType<S>,
+    M: GraphQLType<S>,
+    Sub: GraphQLType<S>,
+    S: ScalarValue,
+{
+    print(&root_node.schema, false)
+}
+
+/// Returns the SDL of the subgraph `schema`, which leaves out the fields
+/// [Federation](crate::Federation) adds.
+pub(crate) fn subgraph_sdl<S: ScalarValue>(schema: &SchemaType<'_, S>) -> String {
+    print(schema, true)
+}
+
+fn print<S: ScalarValue>(schema: &SchemaType<'_, S>, subgraph: bool) -> String {
+    let query_type_name = schema.concrete_query_type().name();
+    let mut definitions = vec![schema_definition(schema)];
+
+    let mut directives = schema
+        .directive_list()
+        .into_iter()
+        .filter(|directive| !BUILT_IN_DIRECTIVES.contains(&directive.name.as_str()))
+        .collect::<Vec<_>>();
+    directives.sort_by(|left, right| left.name.cmp(&right.name));
+    definitions.extend(directives.into_iter().map(|directive| {
+        let mut definition = description(directive.description.as_deref(), "");
+        let _ = write!(
+            definition,
+            "directive @{}{}{} on {}",
+            directive.name,
+            arguments(Some(&directive.arguments)),
+            if directive.is_repeatable {
+                " repeatable"
+            } else {
+                ""
+            },
+            directive
+                .locations
+                .iter()
+                .map(|location| location.to_string().to_uppercase().replace(' ', "_"))
+                .collect::<Vec<_>>()
+                .join(" | "),
+        );
+        definition
+    }));
+
+    let mut types = schema
+        .concrete_type_list()
+        .into_iter()
+        .filter_map(|meta| {
+            let name = meta.name()?;
+            let is_built_in = name.starts_with("__") || BUILT_IN_SCALARS.contains(&name);
+            // `EmptyMutation` and `EmptySubscription` register objects without fields, which
+            // can't be written in SDL, and aren't root types.
+            let is_empty = match meta {
+                MetaType::Object(object) => object
+                    .fields
+                    .iter()
+                    .all(|field| field.name.starts_with("__")),
+                _ => false,
+            };
+            let is_federation = subgraph && name == SERVICE_TYPE;
+            (!is_built_in && !is_empty && !is_federation).then(|| (kind_order(meta), name, meta))
+        })
+        .collect::<Vec<_>>();
+    types.sort_by(|left, right| (left.0, left.1).cmp(&(right.0, right.1)));
+    definitions.extend(types.into_iter().filter_map(|(_, name, meta)| {
+        let hidden_field = (subgraph && Some(name) == query_type_name).then_some(SERVICE_FIELD);
+        type_definition(meta, hidden_field)
+    }));
+
+    let mut sdl = definitions.join("\n\n");
+    sdl.push('\n');
+    sdl
+}
+
+fn schema_definition<S>(schema: &SchemaType<'_, S>) -> String {
+    let mut definition = String::from("schema {\n");
+    let roots = [
+        ("query", schema.concrete_query_type().name()),
+        (
+            "mutation",
+            schema.concrete_mutation_type().and_then(MetaType::name),
+        ),
+        (
+            "subscription",
+            schema.concrete_subscription_type().and_then(MetaType::name),
+        ),
+    ];
+    for (operation, name) in roots {
+        if let Some(name) = name {
+            let _ = writeln!(definition, "  {operation}: {name}");
+        }
+    }
+    definition.push('}');
+    definition
+}
+
+/// Position of the kind of `meta` in the SDL.
+fn kind_order<S>(meta: &MetaType<'_, S>) -> u8 {
+    match meta {
+        MetaType::Scalar(_) => 0,
+        MetaType::Object(_) => 1,
+        MetaType::Interface(_) => 2,
+        MetaType::Union(_) => 3,
+        MetaType::Enum(_) => 4,
+        MetaType::InputObject(_) => 5,
+        MetaType::List(_) | MetaType::Nullable(_) | MetaType::Placeholder(_) => 6,
+    }
+}
+
+/// Returns the definition of `meta`, leaving out its field named `hidden_field`, if any.
+fn type_definition<S: ScalarValue>(
+    meta: &MetaType<'_, S>,
+    hidden_field: Option<&str>,
+) -> Option<String> {
+    let (keyword, name, type_description, body) = match meta {
+        MetaType::Scalar(scalar) => {
+            let specified_by = scalar
+                .specified_by_url
+                .as_ref()
+                .map(|url| format!("@specifiedBy(url: {})", string(url)))
+                .unwrap_or_default();
+            ("scalar", &scalar.name, &scalar.description, specified_by)
+        }
+        MetaType::Object(object) => (
+            "type",
+            &object.name,
+            &object.description,
+            format!(
+                "{} {}",
+                implements(&object.interface_names),
+                fields(&object.fields, hidden_field)
+            )
+            .trim_start()
+            .to_owned(),
+        ),
+        MetaType::Interface(interface) => (
+            "interface",
+            &interface.name,
+            &interface.description,
+            format!(
+                "{} {}",
+                implements(&interface.interface_names),
+                fields(&interface.fields, hidden_field)
+            )
+            .trim_start()
+            .to_owned(),
+        ),
+        MetaType::Union(union) => {
+            let mut members = union.of_type_names.clone();
+            members.sort();
+            (
+                "union",
+                &union.name,
+                &union.description,
+                format!("= {}", members.join(" | ")),
+            )
+        }
+        MetaType::Enum(enum_meta) => {
+            let mut body = String::from("{\n");
+            for value in &enum_meta.values {
+                body.push_str(&description(value.description.as_deref(), "  "));
+                let _ = writeln!(
+                    body,
+                    "  {}{}",
+                    value.name,
+                    deprecated(&value.deprecation_status)
+                );
+            }
+            body.push('}');
+            ("enum", &enum_meta.name, &enum_meta.description, body)
+        }
+        MetaType::InputObject(input_object) => {
+            let mut body = String::from("{\n");
+            for field in &input_object.input_fields {
+                body.push_str(&description(field.description.as_deref(), "  "));
+                let _ = writeln!(body, "  {}", input_value(field));
+            }
+            body.push('}');
+            ("input", &input_object.name, &input_object.description, body)
+        }
+        MetaType::List(_) | MetaType::Nullable(_) | MetaType::Placeholder(_) => return None,
+    };
+    let mut definition = description(type_description.as_deref(), "");
+    let _ = write!(definition, "{keyword} {name}");
+    if !body.is_empty() {
+        let _ = write!(definition, " {body}");
+    }
+    Some(definition)
+}
+
+fn implements(interface_names: &[String]) -> String {
+    if interface_names.is_empty() {
+        return String::new();
+    }
+    let mut interface_names = interface_names.to_vec();
+    interface_names.sort();
+    format!("implements {}", interface_names.join(" & "))
+}
+
+/// Lists `fields` but `hidden_field` in the order they are registered, which is the order of the
+/// schema.
+fn fields<S: ScalarValue>(fields: &[Field<'_, S>], hidden_field: Option<&str>) -> String {
+    let mut body = String::from("{\n");
+    for field in fields
+        .iter()
+        .filter(|field| !field.name.starts_with("__") && Some(field.name.as_str()) != hidden_field)
+    {
+        body.push_str(&description(field.description.as_deref(), "  "));
+        let _ = writeln!(
+            body,
+            "  {}{}: {}{}",
+            field.name,
+            arguments(field.arguments.as_deref()),
+            field.field_type,
+            deprecated(&field.deprecation_status),
+        );
+    }
+    body.push('}');
+    body
+}
+
+fn arguments<S: ScalarValue>(arguments: Option<&[Argument<'_, S>]>) -> String {
+    match arguments {
+        Some(arguments) if !arguments.is_empty() => format!(
+            "({})",
+            arguments
+                .iter()
+                .map(input_value)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        _ => String::new(),
+    }
+}
+
+fn input_value<S: ScalarValue>(argument: &Argument<'_, S>) -> String {
+    let mut input_value = format!("{}: {}", argument.name, argument.arg_type);
+    if let Some(default_value) = &argument.default_value {
+        let _ = write!(input_value, " = {default_value}");
+    }
+    input_value
+}
+
+fn deprecated(deprecation_status: &DeprecationStatus) -> String {
+    match deprecation_status {
+        DeprecationStatus::Current => String::new(),
+        DeprecationStatus::Deprecated(None) => String::from(" @deprecated"),
+        DeprecationStatus::Deprecated(Some(reason)) => {
+            format!(" @deprecated(reason: {})", string(reason))
+        }
+    }
+}
+
+/// Returns the description line of a definition indented by `indent`, if it has a description.
+fn description(description: Option<&str>, indent: &str) -> String {
+    match description {
+        Some(description) if description.contains('\n') => {
+            let mut block = format!("{indent}\"\"\"\n");
+            for line in description.lines() {
+                if line.is_empty() {
+                    block.push('\n');
+                } else {
+                    let _ = writeln!(block, "{indent}{}", line.replace("\"\"\"", "\\\"\"\""));
+                }
+            }
+            let _ = writeln!(block, "{indent}\"\"\"");
+            block
+        }
+        Some(description) => format!("{indent}{}\n", string(description)),
+        None => String::new(),
+    }
+}
+
+/// Returns `value` as a GraphQL string literal.
+fn string(value: &str) -> String {
+    let mut literal = String::from("\"");
+    for char in value.chars() {
+        match char {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            char if char.is_control() => {
+                let _ = write!(literal, "\\u{:04X}", u32::from(char));
+            }
+            char => literal.push(char),
+        }
+    }
+    literal.push('"');
+    literal
+}